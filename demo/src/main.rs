@@ -18,15 +18,14 @@ use daisy_rsx::marketing::{
     webinar::WebinarHeader,
 };
 use daisy_rsx::{
-    Accordian, Alert, AlertColor, AppLayout, Avatar, AvatarSize, AvatarType, Badge, BadgeColor,
-    BadgeSize, BadgeStyle, BlankSlate, Breadcrumb, BreadcrumbItem, Button, ButtonScheme,
-    ButtonShape, ButtonSize, ButtonStyle, Card, CardBody, CardHeader, CheckBox, CheckBoxScheme,
-    Direction, Drawer, DrawerBody, DrawerFooter, DropDown, DropDownLink, Fieldset, FileInput,
-    FileInputColor, Input, Loading, LoadingColor, LoadingSize, LoadingType, Modal, ModalAction,
-    ModalBody, NavGroup, NavItem, Pagination, Range, RangeColor, RelativeTime, RelativeTimeFormat,
-    Select, SelectOption, TabContainer, TabPanel, TextArea, TimeLine, TimeLineBadge, TimeLineBody,
-    Timeline, TimelineDirection, TimelineEnd, TimelineItem, TimelineMiddle, TimelineStart, ToolTip,
-    ToolTipColor,
+    Accordian, Alert, AppLayout, Avatar, AvatarSize, AvatarType, Badge, BadgeStyle, BlankSlate,
+    Breadcrumb, BreadcrumbItem, Button, ButtonShape, ButtonStyle, Card, CardBody, CardHeader,
+    CheckBox, Color, Direction, Drawer, DrawerBody, DrawerFooter, DropDown, DropDownLink, Fieldset,
+    FileInput, Input, Loading, LoadingType, Modal, ModalAction, ModalBody, ModalClose, NavGroup,
+    NavItem, Pagination, Range, RelativeTime, RelativeTimeFormat, Select, SelectOption, Size,
+    TabContainer, TabPanel, TextArea, Theme, ThemeController, ThemeControllerStyle, TimeLine,
+    TimeLineBadge, TimeLineBody, Timeline, TimelineDirection, TimelineEnd, TimelineItem,
+    TimelineMiddle, TimelineStart, ToolTip,
 };
 use dioxus::prelude::*;
 
@@ -143,6 +142,11 @@ pub fn DaisyRsxKitchenSink() -> Element {
             },
             header: rsx! {
                 h1 { class: "text-xl font-semibold ml-4", "Kitchen Sink Demo" }
+                ThemeController {
+                    class: "ml-auto",
+                    themes: vec![Theme::Light, Theme::Dark, Theme::Corporate, Theme::Synthwave],
+                    controller_style: ThemeControllerStyle::Dropdown,
+                }
             },
 
             // Main content
@@ -164,23 +168,23 @@ pub fn DaisyRsxKitchenSink() -> Element {
 
                     ComponentDemo { label: "Button Schemes".to_string(),
                         div { class: "flex flex-wrap gap-2",
-                            Button { button_scheme: ButtonScheme::Primary, "Primary" }
-                            Button { button_scheme: ButtonScheme::Secondary, "Secondary" }
-                            Button { button_scheme: ButtonScheme::Accent, "Accent" }
-                            Button { button_scheme: ButtonScheme::Neutral, "Neutral" }
-                            Button { button_scheme: ButtonScheme::Info, "Info" }
-                            Button { button_scheme: ButtonScheme::Success, "Success" }
-                            Button { button_scheme: ButtonScheme::Warning, "Warning" }
-                            Button { button_scheme: ButtonScheme::Error, "Error" }
+                            Button { button_scheme: Color::Primary, "Primary" }
+                            Button { button_scheme: Color::Secondary, "Secondary" }
+                            Button { button_scheme: Color::Accent, "Accent" }
+                            Button { button_scheme: Color::Neutral, "Neutral" }
+                            Button { button_scheme: Color::Info, "Info" }
+                            Button { button_scheme: Color::Success, "Success" }
+                            Button { button_scheme: Color::Warning, "Warning" }
+                            Button { button_scheme: Color::Error, "Error" }
                         }
                     }
 
                     ComponentDemo { label: "Button Sizes".to_string(),
                         div { class: "flex flex-wrap gap-2 items-center",
-                            Button { button_size: ButtonSize::Large, "Large" }
-                            Button { button_size: ButtonSize::Medium, "Medium" }
-                            Button { button_size: ButtonSize::Small, "Small" }
-                            Button { button_size: ButtonSize::ExtraSmall, "Extra Small" }
+                            Button { button_size: Size::Large, "Large" }
+                            Button { button_size: Size::Medium, "Medium" }
+                            Button { button_size: Size::Small, "Small" }
+                            Button { button_size: Size::ExtraSmall, "Extra Small" }
                         }
                     }
 
@@ -208,23 +212,23 @@ pub fn DaisyRsxKitchenSink() -> Element {
                             }
                             ToolTip {
                                 text: "Info tooltip".to_string(),
-                                tooltip_color: ToolTipColor::Info,
-                                Button { button_scheme: ButtonScheme::Info, "Info" }
+                                tooltip_color: Color::Info,
+                                Button { button_scheme: Color::Info, "Info" }
                             }
                             ToolTip {
                                 text: "Success tooltip".to_string(),
-                                tooltip_color: ToolTipColor::Success,
-                                Button { button_scheme: ButtonScheme::Success, "Success" }
+                                tooltip_color: Color::Success,
+                                Button { button_scheme: Color::Success, "Success" }
                             }
                             ToolTip {
                                 text: "Warning tooltip".to_string(),
-                                tooltip_color: ToolTipColor::Warn,
-                                Button { button_scheme: ButtonScheme::Warning, "Warning" }
+                                tooltip_color: Color::Warning,
+                                Button { button_scheme: Color::Warning, "Warning" }
                             }
                             ToolTip {
                                 text: "Error tooltip".to_string(),
-                                tooltip_color: ToolTipColor::Error,
-                                Button { button_scheme: ButtonScheme::Error, "Error" }
+                                tooltip_color: Color::Error,
+                                Button { button_scheme: Color::Error, "Error" }
                             }
                         }
                     }
@@ -262,16 +266,16 @@ pub fn DaisyRsxKitchenSink() -> Element {
 
                     ComponentDemo { label: "Alert".to_string(),
                         div { class: "flex flex-col gap-2",
-                            Alert { alert_color: AlertColor::Info,
+                            Alert { alert_color: Color::Info,
                                 "This is an info alert - providing helpful information."
                             }
-                            Alert { alert_color: AlertColor::Success,
+                            Alert { alert_color: Color::Success,
                                 "This is a success alert - operation completed!"
                             }
-                            Alert { alert_color: AlertColor::Warn,
+                            Alert { alert_color: Color::Warning,
                                 "This is a warning alert - proceed with caution."
                             }
-                            Alert { alert_color: AlertColor::Error,
+                            Alert { alert_color: Color::Error,
                                 "This is an error alert - something went wrong."
                             }
                         }
@@ -280,14 +284,14 @@ pub fn DaisyRsxKitchenSink() -> Element {
                     ComponentDemo { label: "Badge Colors".to_string(),
                         div { class: "flex flex-wrap gap-2",
                             Badge { "Default" }
-                            Badge { badge_color: BadgeColor::Neutral, "Neutral" }
-                            Badge { badge_color: BadgeColor::Primary, "Primary" }
-                            Badge { badge_color: BadgeColor::Secondary, "Secondary" }
-                            Badge { badge_color: BadgeColor::Accent, "Accent" }
-                            Badge { badge_color: BadgeColor::Info, "Info" }
-                            Badge { badge_color: BadgeColor::Success, "Success" }
-                            Badge { badge_color: BadgeColor::Warning, "Warning" }
-                            Badge { badge_color: BadgeColor::Error, "Error" }
+                            Badge { badge_color: Color::Neutral, "Neutral" }
+                            Badge { badge_color: Color::Primary, "Primary" }
+                            Badge { badge_color: Color::Secondary, "Secondary" }
+                            Badge { badge_color: Color::Accent, "Accent" }
+                            Badge { badge_color: Color::Info, "Info" }
+                            Badge { badge_color: Color::Success, "Success" }
+                            Badge { badge_color: Color::Warning, "Warning" }
+                            Badge { badge_color: Color::Error, "Error" }
                         }
                     }
 
@@ -302,11 +306,11 @@ pub fn DaisyRsxKitchenSink() -> Element {
 
                     ComponentDemo { label: "Badge Sizes".to_string(),
                         div { class: "flex flex-wrap gap-2 items-center",
-                            Badge { badge_size: BadgeSize::Xl, "XL" }
-                            Badge { badge_size: BadgeSize::Lg, "Large" }
-                            Badge { badge_size: BadgeSize::Md, "Medium" }
-                            Badge { badge_size: BadgeSize::Sm, "Small" }
-                            Badge { badge_size: BadgeSize::Xs, "XS" }
+                            Badge { badge_size: Size::ExtraLarge, "XL" }
+                            Badge { badge_size: Size::Large, "Large" }
+                            Badge { badge_size: Size::Medium, "Medium" }
+                            Badge { badge_size: Size::Small, "Small" }
+                            Badge { badge_size: Size::ExtraSmall, "XS" }
                         }
                     }
 
@@ -326,42 +330,42 @@ pub fn DaisyRsxKitchenSink() -> Element {
                             div { class: "flex flex-col items-center gap-2",
                                 Loading {
                                     loading_type: LoadingType::Spinner,
-                                    loading_size: LoadingSize::Large,
+                                    loading_size: Size::Large,
                                 }
                                 span { class: "text-sm", "Spinner" }
                             }
                             div { class: "flex flex-col items-center gap-2",
                                 Loading {
                                     loading_type: LoadingType::Dots,
-                                    loading_size: LoadingSize::Large,
+                                    loading_size: Size::Large,
                                 }
                                 span { class: "text-sm", "Dots" }
                             }
                             div { class: "flex flex-col items-center gap-2",
                                 Loading {
                                     loading_type: LoadingType::Ring,
-                                    loading_size: LoadingSize::Large,
+                                    loading_size: Size::Large,
                                 }
                                 span { class: "text-sm", "Ring" }
                             }
                             div { class: "flex flex-col items-center gap-2",
                                 Loading {
                                     loading_type: LoadingType::Ball,
-                                    loading_size: LoadingSize::Large,
+                                    loading_size: Size::Large,
                                 }
                                 span { class: "text-sm", "Ball" }
                             }
                             div { class: "flex flex-col items-center gap-2",
                                 Loading {
                                     loading_type: LoadingType::Bars,
-                                    loading_size: LoadingSize::Large,
+                                    loading_size: Size::Large,
                                 }
                                 span { class: "text-sm", "Bars" }
                             }
                             div { class: "flex flex-col items-center gap-2",
                                 Loading {
                                     loading_type: LoadingType::Infinity,
-                                    loading_size: LoadingSize::Large,
+                                    loading_size: Size::Large,
                                 }
                                 span { class: "text-sm", "Infinity" }
                             }
@@ -371,23 +375,23 @@ pub fn DaisyRsxKitchenSink() -> Element {
                     ComponentDemo { label: "Loading Sizes".to_string(),
                         div { class: "flex flex-wrap gap-4 items-end",
                             div { class: "flex flex-col items-center gap-2",
-                                Loading { loading_size: LoadingSize::ExtraSmall }
+                                Loading { loading_size: Size::ExtraSmall }
                                 span { class: "text-sm", "XS" }
                             }
                             div { class: "flex flex-col items-center gap-2",
-                                Loading { loading_size: LoadingSize::Small }
+                                Loading { loading_size: Size::Small }
                                 span { class: "text-sm", "Small" }
                             }
                             div { class: "flex flex-col items-center gap-2",
-                                Loading { loading_size: LoadingSize::Medium }
+                                Loading { loading_size: Size::Medium }
                                 span { class: "text-sm", "Medium" }
                             }
                             div { class: "flex flex-col items-center gap-2",
-                                Loading { loading_size: LoadingSize::Large }
+                                Loading { loading_size: Size::Large }
                                 span { class: "text-sm", "Large" }
                             }
                             div { class: "flex flex-col items-center gap-2",
-                                Loading { loading_size: LoadingSize::ExtraLarge }
+                                Loading { loading_size: Size::ExtraLarge }
                                 span { class: "text-sm", "XL" }
                             }
                         }
@@ -396,36 +400,36 @@ pub fn DaisyRsxKitchenSink() -> Element {
                     ComponentDemo { label: "Loading Colors".to_string(),
                         div { class: "flex flex-wrap gap-4 items-center",
                             Loading {
-                                loading_size: LoadingSize::Large,
-                                loading_color: LoadingColor::Primary,
+                                loading_size: Size::Large,
+                                loading_color: Color::Primary,
                             }
                             Loading {
-                                loading_size: LoadingSize::Large,
-                                loading_color: LoadingColor::Secondary,
+                                loading_size: Size::Large,
+                                loading_color: Color::Secondary,
                             }
                             Loading {
-                                loading_size: LoadingSize::Large,
-                                loading_color: LoadingColor::Accent,
+                                loading_size: Size::Large,
+                                loading_color: Color::Accent,
                             }
                             Loading {
-                                loading_size: LoadingSize::Large,
-                                loading_color: LoadingColor::Neutral,
+                                loading_size: Size::Large,
+                                loading_color: Color::Neutral,
                             }
                             Loading {
-                                loading_size: LoadingSize::Large,
-                                loading_color: LoadingColor::Info,
+                                loading_size: Size::Large,
+                                loading_color: Color::Info,
                             }
                             Loading {
-                                loading_size: LoadingSize::Large,
-                                loading_color: LoadingColor::Success,
+                                loading_size: Size::Large,
+                                loading_color: Color::Success,
                             }
                             Loading {
-                                loading_size: LoadingSize::Large,
-                                loading_color: LoadingColor::Warning,
+                                loading_size: Size::Large,
+                                loading_color: Color::Warning,
                             }
                             Loading {
-                                loading_size: LoadingSize::Large,
-                                loading_color: LoadingColor::Error,
+                                loading_size: Size::Large,
+                                loading_color: Color::Error,
                             }
                         }
                     }
@@ -517,7 +521,7 @@ pub fn DaisyRsxKitchenSink() -> Element {
                                 label: "Primary checkbox",
                                 name: "check2",
                                 value: "2",
-                                checkbox_scheme: CheckBoxScheme::Primary,
+                                checkbox_scheme: Color::Primary,
                             }
                             CheckBox {
                                 label: "Checked checkbox",
@@ -543,7 +547,7 @@ pub fn DaisyRsxKitchenSink() -> Element {
                                 min: "0",
                                 max: "100",
                                 value: "75",
-                                range_color: RangeColor::Success,
+                                range_color: Color::Success,
                             }
                             Range {
                                 label: "Error Range",
@@ -551,7 +555,7 @@ pub fn DaisyRsxKitchenSink() -> Element {
                                 min: "0",
                                 max: "100",
                                 value: "25",
-                                range_color: RangeColor::Error,
+                                range_color: Color::Error,
                             }
                         }
                     }
@@ -562,12 +566,12 @@ pub fn DaisyRsxKitchenSink() -> Element {
                             FileInput {
                                 label: "Primary",
                                 name: "file-primary",
-                                file_input_color: FileInputColor::Primary,
+                                file_input_color: Color::Primary,
                             }
                             FileInput {
                                 label: "Secondary",
                                 name: "file-secondary",
-                                file_input_color: FileInputColor::Secondary,
+                                file_input_color: Color::Secondary,
                             }
                         }
                     }
@@ -644,7 +648,7 @@ pub fn DaisyRsxKitchenSink() -> Element {
                         div { class: "grid grid-cols-1 md:grid-cols-2 gap-4",
                             Card { class: "bg-base-200".to_string(),
                                 CardHeader { title: "Card Title".to_string(),
-                                    Badge { badge_color: BadgeColor::Primary, "New" }
+                                    Badge { badge_color: Color::Primary, "New" }
                                 }
                                 CardBody {
                                     p {
@@ -654,7 +658,7 @@ pub fn DaisyRsxKitchenSink() -> Element {
                             }
                             Card { class: "bg-base-200".to_string(),
                                 CardHeader { title: "Another Card".to_string(),
-                                    Badge { badge_color: BadgeColor::Success, "Active" }
+                                    Badge { badge_color: Color::Success, "Active" }
                                 }
                                 CardBody {
                                     p {
@@ -767,19 +771,18 @@ pub fn DaisyRsxKitchenSink() -> Element {
                         div {
                             Button {
                                 popovertarget: "demo-modal",
-                                button_scheme: ButtonScheme::Primary,
+                                button_scheme: Color::Primary,
                                 "Open Modal"
                             }
-                            Modal { trigger_id: "demo-modal".to_string(),
+                            Modal { trigger_id: "demo-modal".to_string(), title: "Modal Title",
                                 ModalBody {
-                                    h3 { class: "font-bold text-lg", "Modal Title" }
-                                    p { class: "py-4",
+                                    p {
                                         "This is the modal content. Modals are useful for confirmations, forms, and displaying additional information without leaving the page."
                                     }
                                 }
                                 ModalAction {
-                                    Button { button_style: ButtonStyle::Ghost, "Cancel" }
-                                    Button { button_scheme: ButtonScheme::Primary, "Confirm" }
+                                    ModalClose { trigger_id: "demo-modal", "Cancel" }
+                                    Button { button_scheme: Color::Primary, "Confirm" }
                                 }
                             }
                         }
@@ -787,9 +790,9 @@ pub fn DaisyRsxKitchenSink() -> Element {
 
                     ComponentDemo { label: "Drawer".to_string(),
                         div {
-                            a {
-                                href: "#demo-drawer",
-                                class: "btn btn-secondary",
+                            Button {
+                                button_scheme: Color::Secondary,
+                                popovertarget: "demo-drawer",
                                 "Open Drawer"
                             }
                             Drawer {
@@ -808,8 +811,8 @@ pub fn DaisyRsxKitchenSink() -> Element {
                                     }
                                 }
                                 DrawerFooter {
-                                    Button { button_style: ButtonStyle::Ghost, "Cancel" }
-                                    Button { button_scheme: ButtonScheme::Primary, "Save" }
+                                    ModalClose { trigger_id: "demo-drawer", "Cancel" }
+                                    Button { button_scheme: Color::Primary, "Save" }
                                 }
                             }
                         }
@@ -0,0 +1,337 @@
+//! Derive macros for `daisy_rsx`. Use them through the re-exports in
+//! `daisy_rsx` rather than depending on this crate directly.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Error, Fields, Ident, LitFloat, LitStr, Result, Type, parse_macro_input,
+};
+
+/// Implements `daisy_rsx::DaisyForm` for a struct with named fields, or
+/// `daisy_rsx::FormField` for an enum of unit variants so it can be used as
+/// a field and rendered as a `Select`.
+///
+/// Struct attributes: `#[daisy(legend = "...")]`.
+///
+/// Field attributes: `label`, `hint`, `name`, `widget` (`"textarea"`,
+/// `"checkbox"`, `"range"` or an input type such as `"email"`), `min`,
+/// `max` and `step` for ranges, `required` for non-`Option` fields and `skip`.
+///
+/// Variant attributes: `label` and `value`.
+///
+/// Misplaced attributes are compile errors rather than being ignored: each
+/// one must be written where it's listed above.
+#[proc_macro_derive(DaisyForm, attributes(daisy))]
+pub fn derive_daisy_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let expanded = match &input.data {
+        Data::Struct(_) => derive_struct(&input),
+        Data::Enum(_) => derive_enum(&input),
+        Data::Union(_) => Err(Error::new_spanned(
+            &input.ident,
+            "DaisyForm can't be derived for unions",
+        )),
+    };
+    expanded.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Where a `#[daisy(...)]` attribute is written.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Position {
+    Struct,
+    Field,
+    Enum,
+    Variant,
+}
+
+impl Position {
+    fn describe(&self) -> &'static str {
+        match self {
+            Position::Struct => "structs",
+            Position::Field => "fields",
+            Position::Enum => "enums",
+            Position::Variant => "enum variants",
+        }
+    }
+}
+
+/// The positions `key` can be written in, or `None` for unknown keys.
+fn positions(key: &str) -> Option<&'static [Position]> {
+    match key {
+        "legend" => Some(&[Position::Struct]),
+        "label" => Some(&[Position::Field, Position::Variant]),
+        "value" => Some(&[Position::Variant]),
+        "hint" | "name" | "widget" | "min" | "max" | "step" | "required" | "skip" => {
+            Some(&[Position::Field])
+        }
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct Options {
+    label: Option<LitStr>,
+    hint: Option<LitStr>,
+    name: Option<LitStr>,
+    value: Option<LitStr>,
+    legend: Option<LitStr>,
+    widget: Option<LitStr>,
+    min: Option<LitFloat>,
+    max: Option<LitFloat>,
+    step: Option<LitFloat>,
+    /// The `required` keyword, kept for its span
+    required: Option<Ident>,
+    skip: bool,
+}
+
+impl Options {
+    fn parse(attrs: &[syn::Attribute], position: Position) -> Result<Self> {
+        let mut options = Options::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("daisy")) {
+            attr.parse_nested_meta(|meta| {
+                let key = meta.path.get_ident().map(Ident::to_string);
+                let Some(allowed) = key.as_deref().and_then(positions) else {
+                    return Err(meta.error("unknown daisy attribute"));
+                };
+                if !allowed.contains(&position) {
+                    let places: Vec<_> = allowed.iter().map(Position::describe).collect();
+                    return Err(meta.error(format!(
+                        "`{}` can only be used on {}",
+                        key.unwrap_or_default(),
+                        places.join(" and ")
+                    )));
+                }
+                match key.as_deref() {
+                    Some("label") => options.label = Some(meta.value()?.parse()?),
+                    Some("hint") => options.hint = Some(meta.value()?.parse()?),
+                    Some("name") => options.name = Some(meta.value()?.parse()?),
+                    Some("value") => options.value = Some(meta.value()?.parse()?),
+                    Some("legend") => options.legend = Some(meta.value()?.parse()?),
+                    Some("widget") => options.widget = Some(meta.value()?.parse()?),
+                    Some("min") => options.min = Some(number(meta.value()?)?),
+                    Some("max") => options.max = Some(number(meta.value()?)?),
+                    Some("step") => options.step = Some(number(meta.value()?)?),
+                    Some("required") => options.required = meta.path.get_ident().cloned(),
+                    Some("skip") => options.skip = true,
+                    _ => unreachable!("checked by `positions`"),
+                }
+                Ok(())
+            })?;
+        }
+        Ok(options)
+    }
+}
+
+/// Accepts integer or float literals, so `min = 0` works as well as `min = 0.5`.
+fn number(input: syn::parse::ParseStream) -> Result<LitFloat> {
+    let lit: syn::Lit = input.parse()?;
+    match lit {
+        syn::Lit::Int(int) => Ok(LitFloat::new(
+            &format!("{}f64", int.base10_digits()),
+            int.span(),
+        )),
+        syn::Lit::Float(float) => Ok(LitFloat::new(
+            &format!("{}f64", float.base10_digits()),
+            float.span(),
+        )),
+        other => Err(Error::new_spanned(other, "expected a number")),
+    }
+}
+
+fn derive_struct(input: &DeriveInput) -> Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        unreachable!()
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "DaisyForm needs a struct with named fields",
+        ));
+    };
+
+    let ident = &input.ident;
+    let options = Options::parse(&input.attrs, Position::Struct)?;
+    let legend = options
+        .legend
+        .map(|legend| legend.value())
+        .unwrap_or_else(|| humanize(&ident.to_string()));
+
+    let mut controls = Vec::new();
+    for field in &fields.named {
+        let options = Options::parse(&field.attrs, Position::Field)?;
+        if options.skip {
+            continue;
+        }
+        let member = field.ident.as_ref().expect("named field");
+        let name = options
+            .name
+            .as_ref()
+            .map(LitStr::value)
+            .unwrap_or_else(|| member.to_string());
+        let label = options
+            .label
+            .as_ref()
+            .map(LitStr::value)
+            .unwrap_or_else(|| humanize(&member.to_string()));
+
+        let mut state = quote! {
+            errors.field(#name, ::daisy_rsx::FormField::form_value(&self.#member))
+        };
+        if let Some(hint) = &options.hint {
+            state = quote! { #state.hint(#hint) };
+        }
+        if let Some(required) = &options.required {
+            if is_option(&field.ty) {
+                return Err(Error::new_spanned(
+                    required,
+                    "`required` can't be used on an `Option` field",
+                ));
+            }
+            state = quote! { #state.required() };
+        }
+
+        let is_range = options
+            .widget
+            .as_ref()
+            .is_some_and(|widget| widget.value() == "range");
+        let bound = [&options.min, &options.max, &options.step]
+            .into_iter()
+            .flatten()
+            .next();
+        if let (false, Some(bound)) = (is_range, bound) {
+            return Err(Error::new_spanned(
+                bound,
+                "`min`, `max` and `step` need `widget = \"range\"`",
+            ));
+        }
+
+        let ty = &field.ty;
+        let widget = match &options.widget {
+            None => quote! { <#ty as ::daisy_rsx::FormField>::widget() },
+            Some(_) => widget(&options)?,
+        };
+        let control = quote! { #widget.render(#label.to_string(), #state) };
+        controls.push(control);
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::daisy_rsx::DaisyForm for #ident #ty_generics #where_clause {
+            const LEGEND: &'static str = #legend;
+
+            fn form_controls(
+                &self,
+                errors: &::daisy_rsx::FormErrors,
+            ) -> ::std::vec::Vec<::dioxus::prelude::Element> {
+                ::std::vec![#(#controls),*]
+            }
+        }
+    })
+}
+
+fn widget(options: &Options) -> Result<TokenStream2> {
+    let widget = options.widget.as_ref().expect("widget attribute");
+    let optional = |value: &Option<LitFloat>| match value {
+        Some(value) => quote! { ::std::option::Option::Some(#value) },
+        None => quote! { ::std::option::Option::None },
+    };
+    Ok(match widget.value().as_str() {
+        "textarea" => quote! { ::daisy_rsx::Widget::TextArea },
+        "checkbox" => quote! { ::daisy_rsx::Widget::CheckBox },
+        "range" => {
+            let (min, max, step) = (
+                optional(&options.min),
+                optional(&options.max),
+                optional(&options.step),
+            );
+            quote! { ::daisy_rsx::Widget::Range { min: #min, max: #max, step: #step } }
+        }
+        "select" => {
+            return Err(Error::new_spanned(
+                widget,
+                "derive DaisyForm on the field's enum to render it as a select",
+            ));
+        }
+        "" => return Err(Error::new_spanned(widget, "widget can't be empty")),
+        input_type => quote! { ::daisy_rsx::Widget::Input(#input_type) },
+    })
+}
+
+fn derive_enum(input: &DeriveInput) -> Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        unreachable!()
+    };
+    Options::parse(&input.attrs, Position::Enum)?;
+
+    let mut arms = Vec::new();
+    let mut choices = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "DaisyForm enums can only have unit variants",
+            ));
+        }
+        let options = Options::parse(&variant.attrs, Position::Variant)?;
+        let ident = &variant.ident;
+        let value = options
+            .value
+            .as_ref()
+            .map(LitStr::value)
+            .unwrap_or_else(|| ident.to_string());
+        let label = options
+            .label
+            .as_ref()
+            .map(LitStr::value)
+            .unwrap_or_else(|| humanize(&ident.to_string()));
+        arms.push(quote! { Self::#ident => #value });
+        choices.push(quote! { (#value.to_string(), #label.to_string()) });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::daisy_rsx::FormField for #ident #ty_generics #where_clause {
+            fn form_value(&self) -> ::std::string::String {
+                match self {
+                    #(#arms,)*
+                }
+                .to_string()
+            }
+
+            fn widget() -> ::daisy_rsx::Widget {
+                ::daisy_rsx::Widget::Select(::std::vec![#(#choices),*])
+            }
+        }
+    })
+}
+
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+/// `first_name` and `FirstName` both become `First name`.
+fn humanize(ident: &str) -> String {
+    let mut words = String::new();
+    let mut previous: Option<char> = None;
+    for c in ident.trim_start_matches("r#").chars() {
+        match previous {
+            _ if c == '_' => words.push(' '),
+            None => words.extend(c.to_uppercase()),
+            Some(p) if c.is_uppercase() && p.is_lowercase() => {
+                words.push(' ');
+                words.extend(c.to_lowercase());
+            }
+            Some(_) => words.extend(c.to_lowercase()),
+        }
+        previous = Some(c);
+    }
+    words.trim().to_string()
+}
@@ -1,43 +1,28 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
-pub enum AlertColor {
-    #[default]
-    Default,
-    Warn,
-    Info,
-    Error,
-    Success,
-}
+use crate::color::{Color, ColorPrefix};
 
-impl Display for AlertColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            AlertColor::Default => write!(f, ""),
-            AlertColor::Info => write!(f, "alert-info"),
-            AlertColor::Warn => write!(f, "alert-warning"),
-            AlertColor::Error => write!(f, "alert-error"),
-            AlertColor::Success => write!(f, "alert-success"),
-        }
-    }
-}
+#[deprecated(since = "0.1.48", note = "use `Color` instead")]
+pub type AlertColor = Color;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct AlertProps {
     pub children: Element,
     #[props(default)]
-    pub alert_color: AlertColor,
+    pub alert_color: Color,
     /// All standard HTML div attributes (id, style, onclick, etc.)
     #[props(extends = div, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+impl ColorPrefix for AlertProps {
+    const COLOR_PREFIX: &'static str = "alert";
+}
+
 #[component]
 pub fn Alert(props: AlertProps) -> Element {
-    let color = props.alert_color.to_string();
+    let color = props.alert_color.class::<AlertProps>();
 
     rsx!(
         div { class: "alert {color}", ..props.attributes, {props.children} }
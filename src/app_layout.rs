@@ -1,20 +1,118 @@
 #![allow(non_snake_case)]
+use std::fmt::Display;
+
 use dioxus::prelude::*;
 
+use crate::csp::{CspNonce, provide_csp_nonce};
+use crate::flash::Flash;
+use crate::page_meta::{PageMeta, PageMetaTags};
+use crate::theme::Theme;
+use crate::toast::Flashes;
+
+/// Id of the checkbox behind the sidebar drawer. A `label { r#for }` pointing
+/// at it anywhere on the page opens and closes the sidebar.
+pub const SIDEBAR_TOGGLE_ID: &str = "sidebar-toggle";
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SidebarWidth {
+    Small,
+    #[default]
+    Medium,
+    Large,
+    ExtraLarge,
+}
+
+impl SidebarWidth {
+    /// The width while an icon-only sidebar is expanded.
+    fn expanded_class(&self) -> &'static str {
+        match self {
+            SidebarWidth::Small => "is-drawer-open:w-56",
+            SidebarWidth::Medium => "is-drawer-open:w-64",
+            SidebarWidth::Large => "is-drawer-open:w-72",
+            SidebarWidth::ExtraLarge => "is-drawer-open:w-80",
+        }
+    }
+}
+
+impl Display for SidebarWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SidebarWidth::Small => write!(f, "w-56"),
+            SidebarWidth::Medium => write!(f, "w-64"),
+            SidebarWidth::Large => write!(f, "w-72"),
+            SidebarWidth::ExtraLarge => write!(f, "w-80"),
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct AppLayoutProps {
-    title: String,
-    fav_icon_src: Option<String>,
-    stylesheets: Vec<String>,
-    js_href: Option<String>,
-    header: Element,
-    children: Element,
-    sidebar: Element,
-    sidebar_footer: Element,
-    sidebar_header: Element,
+    pub title: String,
+    pub fav_icon_src: Option<String>,
+    pub stylesheets: Vec<String>,
+    pub js_href: Option<String>,
+    /// Written as `data-theme` on `<body>`, and on `<html>` by `ssr::render_page`
+    pub theme: Option<Theme>,
+    /// Added to every script and stylesheet and provided to the components
+    /// on the page, see the `csp` module
+    pub csp_nonce: Option<CspNonce>,
+    /// Description, social cards, scripts and other head tags
+    #[props(default)]
+    pub meta: PageMeta,
+    /// Shown as toasts, e.g. messages read back from the flash cookie
+    #[props(default)]
+    pub flashes: Vec<Flash>,
+    #[props(default)]
+    pub sidebar_width: SidebarWidth,
+    /// On large screens collapse the sidebar to icons, with the header
+    /// button expanding it. Hide text in the sidebar with `is-drawer-close:hidden`.
+    #[props(default)]
+    pub sidebar_collapsible: bool,
+    /// Put the sidebar on the right of the page
+    #[props(default)]
+    pub sidebar_right: bool,
+    pub header: Element,
+    pub children: Element,
+    pub sidebar: Element,
+    pub sidebar_footer: Element,
+    pub sidebar_header: Element,
 }
 
+/// The page shell: a sidebar that's always open on large screens and a
+/// drawer behind the header's toggle button on small ones, with no JavaScript.
+///
+/// Dioxus can't render the `<html>` element itself, so this renders its
+/// `head` and `body` only, with `lang` from `meta` and `data-theme` from
+/// `theme` on the `body`. Render full pages with `ssr::render_page` or
+/// `ssr::write_page`, which wrap it in `<html>` carrying both as well.
 pub fn AppLayout(props: AppLayoutProps) -> Element {
+    provide_csp_nonce(props.csp_nonce.clone());
+    let nonce = props.csp_nonce.as_ref().map(|nonce| nonce.0.clone());
+    let theme = props.theme.as_ref().map(|theme| theme.name().to_string());
+    let lang = props.meta.lang.clone();
+    let main = main_content(&props);
+    let side = if props.sidebar_right {
+        "drawer-end"
+    } else {
+        ""
+    };
+    let border = if props.sidebar_right {
+        "border-l border-base-300"
+    } else {
+        "border-r border-base-300"
+    };
+    let (width, side_overflow) = if props.sidebar_collapsible {
+        (
+            format!(
+                "is-drawer-close:w-16 {}",
+                props.sidebar_width.expanded_class()
+            ),
+            "is-drawer-close:overflow-visible",
+        )
+    } else {
+        (props.sidebar_width.to_string(), "")
+    };
+
     rsx!(
         head {
             title { "{props.title}" }
@@ -24,11 +122,21 @@ pub fn AppLayout(props: AppLayoutProps) -> Element {
                 name: "viewport",
                 content: "width=device-width, initial-scale=1",
             }
+            PageMetaTags { meta: props.meta }
             for href in &props.stylesheets {
-                link { rel: "stylesheet", href: "{href}", "type": "text/css" }
+                link {
+                    rel: "stylesheet",
+                    href: "{href}",
+                    "type": "text/css",
+                    nonce: nonce.clone(),
+                }
             }
             if let Some(js_href) = props.js_href {
-                script { "type": "module", src: "{js_href}" }
+                script {
+                    "type": "module",
+                    src: "{js_href}",
+                    nonce: nonce.clone(),
+                }
             }
             if let Some(fav_icon_src) = props.fav_icon_src {
                 link {
@@ -38,62 +146,91 @@ pub fn AppLayout(props: AppLayoutProps) -> Element {
                 }
             }
         }
-        body {
-            div { class: "flex h-screen overflow-hidden",
-                nav {
-                    id: "sidebar",
-                    class: "
-                        border-r border-base-300
-                        fixed
-                        bg-base-200
-                        inset-y-0
-                        left-0
-                        w-64
-                        transform
-                        -translate-x-full
-                        transition-transform
-                        duration-200
-                        ease-in-out
-                        flex
-                        flex-col
-                        lg:translate-x-0
-                        lg:static
-                        lg:inset-auto
-                        lg:transform-none
-                        z-20",
-                    div { class: "flex items-center p-4", {props.sidebar_header} }
-                    div { class: "flex-1 overflow-y-auto", {props.sidebar} }
-                    div { class: "p-4", {props.sidebar_footer} }
+        body { lang, "data-theme": theme,
+            div { class: "drawer lg:drawer-open {side}",
+                input {
+                    r#type: "checkbox",
+                    class: "drawer-toggle",
+                    id: SIDEBAR_TOGGLE_ID,
+                }
+                main {
+                    id: "main-content",
+                    class: "drawer-content flex flex-col h-screen overflow-hidden",
+                    {main}
                 }
-                main { id: "main-content", class: "flex-1 flex flex-col",
-                    header { class: "flex items-center p-4 border-b border-base-300",
-                        button { id: "toggleButton",
-                            svg {
-                                xmlns: "http://www.w3.org/2000/svg",
-                                width: "24",
-                                height: "24",
-                                view_box: "0 0 24 24",
-                                fill: "none",
-                                stroke: "currentColor",
-                                stroke_width: "2",
-                                stroke_linecap: "round",
-                                stroke_linejoin: "round",
-                                class: "lucide lucide-panel-left",
-                                rect {
-                                    width: "18",
-                                    height: "18",
-                                    x: "3",
-                                    y: "3",
-                                    rx: "2",
-                                }
-                                path { d: "M9 3v18" }
-                            }
-                        }
-                        {props.header}
+                div { class: "drawer-side z-20 {side_overflow}",
+                    label {
+                        r#for: SIDEBAR_TOGGLE_ID,
+                        class: "drawer-overlay",
+                        "aria-label": "Close sidebar",
+                    }
+                    nav {
+                        id: "sidebar",
+                        class: "flex flex-col min-h-full bg-base-200 {border} {width}",
+                        div { class: "flex items-center p-4", {props.sidebar_header} }
+                        div { class: "flex-1 overflow-y-auto", {props.sidebar} }
+                        div { class: "p-4", {props.sidebar_footer} }
+                    }
+                }
+            }
+            Flashes { flashes: props.flashes }
+        }
+    )
+}
+
+/// Only the page's title and main content, for requests that swap
+/// `#main-content` (such as htmx navigation) instead of loading the whole page.
+///
+/// The browser keeps the `<html>` element and head from the full page, so
+/// `theme` and everything in `meta` except the title are ignored here;
+/// changing them needs a full page load.
+pub fn AppLayoutContent(props: AppLayoutProps) -> Element {
+    provide_csp_nonce(props.csp_nonce.clone());
+
+    rsx!(
+        title { "{props.title}" }
+        {main_content(&props)}
+        Flashes { flashes: props.flashes }
+    )
+}
+
+/// The inside of `main#main-content`: the header bar and the page body.
+fn main_content(props: &AppLayoutProps) -> Element {
+    let toggle_visibility = if props.sidebar_collapsible {
+        ""
+    } else {
+        "lg:hidden"
+    };
+
+    rsx!(
+        header { class: "flex items-center gap-2 p-4 border-b border-base-300",
+            label {
+                r#for: SIDEBAR_TOGGLE_ID,
+                class: "btn btn-square btn-ghost btn-sm {toggle_visibility}",
+                "aria-label": "Toggle sidebar",
+                svg {
+                    xmlns: "http://www.w3.org/2000/svg",
+                    width: "24",
+                    height: "24",
+                    view_box: "0 0 24 24",
+                    fill: "none",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    stroke_linecap: "round",
+                    stroke_linejoin: "round",
+                    class: "lucide lucide-panel-left",
+                    rect {
+                        width: "18",
+                        height: "18",
+                        x: "3",
+                        y: "3",
+                        rx: "2",
                     }
-                    section { class: "flex-1 overflow-y-auto", {props.children} }
+                    path { d: "M9 3v18" }
                 }
             }
+            {props.header.clone()}
         }
+        section { class: "flex-1 overflow-y-auto", {props.children.clone()} }
     )
 }
@@ -41,7 +41,7 @@ impl AvatarSize {
         match self {
             AvatarSize::Small => ("24", "24", "w-8 h-8"),
             AvatarSize::Medium => ("64", "64", "w-16 h-16"),
-            AvatarSize::Large => ("96", "96", "w-20 w-20"),
+            AvatarSize::Large => ("96", "96", "w-20 h-20"),
             AvatarSize::ExtraLarge => ("128", "128", "w-32 h-32"),
         }
     }
@@ -0,0 +1,212 @@
+//! Axum integration, enabled with the `axum` feature.
+//!
+//! Register a [`PageLayout`] once as an `Extension` and handlers only build
+//! the page body:
+//!
+//! ```rust
+//! use axum::{Extension, Router, routing::get};
+//! use daisy_rsx::app_layout::AppLayoutProps;
+//! use daisy_rsx::axum::{Layout, PageLayout};
+//! use dioxus::prelude::*;
+//!
+//! async fn dashboard(layout: Layout) -> axum::response::Response {
+//!     layout.page("Dashboard", rsx! { h1 { "Dashboard" } })
+//! }
+//!
+//! let layout = PageLayout::new(|content| {
+//!     AppLayoutProps::builder()
+//!         .title(content.title)
+//!         .stylesheets(vec!["/tailwind.css".to_string()])
+//!         .flashes(content.flashes)
+//!         .header(rsx! {})
+//!         .sidebar(rsx! {})
+//!         .sidebar_header(rsx! {})
+//!         .sidebar_footer(rsx! {})
+//!         .children(content.body)
+//!         .build()
+//! });
+//!
+//! let app: Router = Router::new()
+//!     .route("/", get(dashboard))
+//!     .layer(Extension(layout));
+//! ```
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use ::axum::extract::FromRequestParts;
+use ::axum::http::header::{COOKIE, SET_COOKIE, VARY};
+use ::axum::http::request::Parts;
+use ::axum::http::{HeaderValue, StatusCode};
+use ::axum::response::{Html, IntoResponse, IntoResponseParts, Response, ResponseParts};
+use dioxus::prelude::*;
+
+use crate::app_layout::AppLayoutProps;
+use crate::flash::{self, Flash};
+use crate::ssr;
+
+/// A full page rendered with `AppLayout`.
+pub struct Page(pub AppLayoutProps);
+
+impl IntoResponse for Page {
+    fn into_response(self) -> Response {
+        Html(ssr::render_page(self.0)).into_response()
+    }
+}
+
+/// Only the title and main content of a page, for htmx navigation.
+pub struct PageContentOnly(pub AppLayoutProps);
+
+impl IntoResponse for PageContentOnly {
+    fn into_response(self) -> Response {
+        Html(ssr::render_content(self.0)).into_response()
+    }
+}
+
+/// An element rendered on its own, for partial updates.
+pub struct Fragment(pub Element);
+
+impl IntoResponse for Fragment {
+    fn into_response(self) -> Response {
+        Html(ssr::render_fragment(self.0)).into_response()
+    }
+}
+
+/// Whether the request came from htmx wanting part of a page: `HX-Request`
+/// is set and it isn't an `hx-boost` navigation, which swaps the whole body.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HxRequest(pub bool);
+
+impl<S: Send + Sync> FromRequestParts<S> for HxRequest {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let header = |name: &str| parts.headers.get(name).is_some_and(|v| v == "true");
+        Ok(HxRequest(header("hx-request") && !header("hx-boosted")))
+    }
+}
+
+/// The flash messages sent with the request, read from the flash cookie.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FlashMessages(pub Vec<Flash>);
+
+impl<S: Send + Sync> FromRequestParts<S> for FlashMessages {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let flashes = parts
+            .headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|header| header.to_str().ok())
+            .flat_map(flash::from_cookie_header)
+            .collect();
+        Ok(FlashMessages(flashes))
+    }
+}
+
+/// Sets the flash cookie, typically alongside a redirect.
+///
+/// ```rust
+/// # use axum::response::{IntoResponse, Redirect};
+/// # use daisy_rsx::Flash;
+/// # use daisy_rsx::axum::SetFlash;
+/// async fn save() -> impl IntoResponse {
+///     (SetFlash(vec![Flash::success("Saved")]), Redirect::to("/"))
+/// }
+/// ```
+pub struct SetFlash(pub Vec<Flash>);
+
+impl IntoResponseParts for SetFlash {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        if let Ok(value) = HeaderValue::from_str(&flash::set_cookie_header(&self.0)) {
+            res.headers_mut().append(SET_COOKIE, value);
+        }
+        Ok(res)
+    }
+}
+
+/// Removes the flash cookie once its messages have been shown.
+pub struct ClearFlash;
+
+impl IntoResponseParts for ClearFlash {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        if let Ok(value) = HeaderValue::from_str(&flash::clear_cookie_header()) {
+            res.headers_mut().append(SET_COOKIE, value);
+        }
+        Ok(res)
+    }
+}
+
+/// What a handler passes to the [`PageLayout`].
+pub struct PageContent {
+    pub title: String,
+    pub body: Element,
+    pub flashes: Vec<Flash>,
+}
+
+/// Builds the `AppLayoutProps` around each page body. Add it to the router
+/// with `.layer(Extension(layout))`.
+#[derive(Clone)]
+pub struct PageLayout(Arc<dyn Fn(PageContent) -> AppLayoutProps + Send + Sync>);
+
+impl PageLayout {
+    pub fn new(build: impl Fn(PageContent) -> AppLayoutProps + Send + Sync + 'static) -> Self {
+        Self(Arc::new(build))
+    }
+}
+
+/// Extracts the configured [`PageLayout`], the request's flash messages and
+/// whether it's an [`HxRequest`].
+pub struct Layout {
+    layout: PageLayout,
+    flashes: Vec<Flash>,
+    htmx: bool,
+}
+
+impl Layout {
+    /// Wraps `body` in the layout, or renders only the main content for
+    /// htmx requests. Clears the flash cookie if its messages were shown.
+    pub fn page(self, title: impl Into<String>, body: Element) -> Response {
+        let shown = !self.flashes.is_empty();
+        let props = (self.layout.0)(PageContent {
+            title: title.into(),
+            body,
+            flashes: self.flashes,
+        });
+        let mut response = if self.htmx {
+            PageContentOnly(props).into_response()
+        } else {
+            Page(props).into_response()
+        };
+        response
+            .headers_mut()
+            .append(VARY, HeaderValue::from_static("HX-Request"));
+        if shown {
+            (ClearFlash, response).into_response()
+        } else {
+            response
+        }
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Layout {
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let layout = parts.extensions.get::<PageLayout>().cloned().ok_or((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "PageLayout extension is missing",
+        ))?;
+        let Ok(FlashMessages(flashes)) = FlashMessages::from_request_parts(parts, state).await;
+        let Ok(HxRequest(htmx)) = HxRequest::from_request_parts(parts, state).await;
+        Ok(Layout {
+            layout,
+            flashes,
+            htmx,
+        })
+    }
+}
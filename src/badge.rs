@@ -3,6 +3,9 @@ use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::color::{Color, ColorPrefix};
+use crate::size::{Size, SizePrefix};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum BadgeStyle {
     #[default]
@@ -25,57 +28,11 @@ impl Display for BadgeStyle {
     }
 }
 
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum BadgeColor {
-    #[default]
-    Default,
-    Neutral,
-    Primary,
-    Secondary,
-    Accent,
-    Info,
-    Success,
-    Warning,
-    Error,
-}
-
-impl Display for BadgeColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BadgeColor::Default => write!(f, ""),
-            BadgeColor::Neutral => write!(f, "badge-neutral"),
-            BadgeColor::Primary => write!(f, "badge-primary"),
-            BadgeColor::Secondary => write!(f, "badge-secondary"),
-            BadgeColor::Accent => write!(f, "badge-accent"),
-            BadgeColor::Info => write!(f, "badge-info"),
-            BadgeColor::Success => write!(f, "badge-success"),
-            BadgeColor::Warning => write!(f, "badge-warning"),
-            BadgeColor::Error => write!(f, "badge-error"),
-        }
-    }
-}
-
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum BadgeSize {
-    #[default]
-    Md,
-    Xs,
-    Sm,
-    Lg,
-    Xl,
-}
+#[deprecated(since = "0.1.48", note = "use `Color` instead")]
+pub type BadgeColor = Color;
 
-impl Display for BadgeSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BadgeSize::Md => write!(f, "badge-md"),
-            BadgeSize::Xs => write!(f, "badge-xs"),
-            BadgeSize::Sm => write!(f, "badge-sm"),
-            BadgeSize::Lg => write!(f, "badge-lg"),
-            BadgeSize::Xl => write!(f, "badge-xl"),
-        }
-    }
-}
+#[deprecated(since = "0.1.48", note = "use `Size` instead")]
+pub type BadgeSize = Size;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct BadgeProps {
@@ -83,19 +40,28 @@ pub struct BadgeProps {
     #[props(default)]
     pub badge_style: BadgeStyle,
     #[props(default)]
-    pub badge_color: BadgeColor,
-    #[props(default)]
-    pub badge_size: BadgeSize,
+    pub badge_color: Color,
+    /// Defaults to `Size::Medium`, matching the class earlier releases rendered.
+    #[props(default = Size::Medium)]
+    pub badge_size: Size,
     /// All standard HTML span attributes (id, style, onclick, etc.)
     #[props(extends = span, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+impl ColorPrefix for BadgeProps {
+    const COLOR_PREFIX: &'static str = "badge";
+}
+
+impl SizePrefix for BadgeProps {
+    const SIZE_PREFIX: &'static str = "badge";
+}
+
 #[component]
 pub fn Badge(props: BadgeProps) -> Element {
     let style = props.badge_style.to_string();
-    let color = props.badge_color.to_string();
-    let size = props.badge_size.to_string();
+    let color = props.badge_color.class::<BadgeProps>();
+    let size = props.badge_size.class::<BadgeProps>();
 
     rsx!(
         span { class: "badge {style} {color} {size}", ..props.attributes, {props.children} }
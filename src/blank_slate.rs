@@ -1,5 +1,6 @@
 #![allow(non_snake_case)]
-use super::button::{Button, ButtonScheme};
+use super::button::Button;
+use super::color::Color;
 use dioxus::prelude::*;
 
 #[derive(Props, Clone, PartialEq)]
@@ -8,6 +9,7 @@ pub struct BlankSlateProps {
     visual: String,
     description: String,
     primary_action: Option<(String, String)>,
+    /// Label and `trigger_id` of a `Drawer` the action opens
     primary_action_drawer: Option<(String, String)>,
     secondary_action: Option<(String, String)>,
 }
@@ -37,7 +39,7 @@ pub fn BlankSlate(props: BlankSlateProps) -> Element {
             match props.primary_action_drawer {
                 Some(pa) => rsx! {
                     div {
-                        Button { button_scheme: ButtonScheme::Primary, popovertarget: "{pa.1}", "{pa.0}" }
+                        Button { button_scheme: Color::Primary, popovertarget: "{pa.1}", "{pa.0}" }
                     }
                 },
                 None => rsx! {},
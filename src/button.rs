@@ -3,57 +3,9 @@ use std::fmt::Display;
 
 use dioxus::prelude::*;
 
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum ButtonScheme {
-    #[default]
-    Default,
-    Neutral,
-    Primary,
-    Secondary,
-    Accent,
-    Info,
-    Success,
-    Warning,
-    Error,
-}
-
-impl Display for ButtonScheme {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ButtonScheme::Default => write!(f, ""),
-            ButtonScheme::Neutral => write!(f, "btn-neutral"),
-            ButtonScheme::Primary => write!(f, "btn-primary"),
-            ButtonScheme::Secondary => write!(f, "btn-secondary"),
-            ButtonScheme::Accent => write!(f, "btn-accent"),
-            ButtonScheme::Info => write!(f, "btn-info"),
-            ButtonScheme::Success => write!(f, "btn-success"),
-            ButtonScheme::Warning => write!(f, "btn-warning"),
-            ButtonScheme::Error => write!(f, "btn-error"),
-        }
-    }
-}
-
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum ButtonSize {
-    #[default]
-    Default,
-    Small,
-    ExtraSmall,
-    Large,
-    Medium,
-}
-
-impl Display for ButtonSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ButtonSize::Default => write!(f, ""),
-            ButtonSize::ExtraSmall => write!(f, "btn-xs"),
-            ButtonSize::Small => write!(f, "btn-sm"),
-            ButtonSize::Medium => write!(f, "btn-md"),
-            ButtonSize::Large => write!(f, "btn-lg"),
-        }
-    }
-}
+use crate::color::{Color, ColorPrefix};
+use crate::htmx::{Hx, hx_attributes};
+use crate::size::{Size, SizePrefix};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ButtonShape {
@@ -97,40 +49,66 @@ impl Display for ButtonStyle {
     }
 }
 
+#[deprecated(since = "0.1.48", note = "use `Color` instead")]
+pub type ButtonScheme = Color;
+
+#[deprecated(since = "0.1.48", note = "use `Size` instead")]
+pub type ButtonSize = Size;
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ButtonProps {
     pub children: Element,
     #[props(default)]
-    pub button_scheme: ButtonScheme,
+    pub button_scheme: Color,
     #[props(default)]
-    pub button_size: ButtonSize,
+    pub button_size: Size,
     #[props(default)]
     pub button_shape: ButtonShape,
     #[props(default)]
     pub button_style: ButtonStyle,
+    /// htmx request made on click
+    pub hx: Option<Hx>,
     /// All standard HTML button attributes (type, disabled, onclick, etc.)
     #[props(extends = button, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+impl ColorPrefix for ButtonProps {
+    const COLOR_PREFIX: &'static str = "btn";
+}
+
+impl SizePrefix for ButtonProps {
+    const SIZE_PREFIX: &'static str = "btn";
+}
+
 /// A button component with DaisyUI styling.
 ///
 /// # Example
 /// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
 /// Button {
-///     button_scheme: ButtonScheme::Primary,
-///     onclick: move |_| { /* handle click */ },
+///     button_scheme: Color::Primary,
+///     r#type: "submit",
 ///     "Click me"
 /// }
+/// # }}
 /// ```
 #[component]
 pub fn Button(props: ButtonProps) -> Element {
-    let scheme = props.button_scheme.to_string();
-    let size = props.button_size.to_string();
+    let scheme = props.button_scheme.class::<ButtonProps>();
+    let size = props.button_size.class::<ButtonProps>();
     let shape = props.button_shape.to_string();
     let style = props.button_style.to_string();
+    let hx = hx_attributes(&props.hx);
 
     rsx!(
-        button { class: "btn {scheme} {size} {shape} {style}", ..props.attributes, {props.children} }
+        button {
+            class: "btn {scheme} {size} {shape} {style}",
+            ..hx,
+            ..props.attributes,
+            {props.children}
+        }
     )
 }
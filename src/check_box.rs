@@ -1,84 +1,70 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum CheckBoxScheme {
-    #[default]
-    Default,
-    Primary,
-    Secondary,
-    Accent,
-    Info,
-    Success,
-    Warning,
-    Error,
-}
-
-impl Display for CheckBoxScheme {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            CheckBoxScheme::Default => write!(f, ""),
-            CheckBoxScheme::Primary => write!(f, "checkbox-primary"),
-            CheckBoxScheme::Secondary => write!(f, "checkbox-secondary"),
-            CheckBoxScheme::Accent => write!(f, "checkbox-accent"),
-            CheckBoxScheme::Info => write!(f, "checkbox-info"),
-            CheckBoxScheme::Success => write!(f, "checkbox-success"),
-            CheckBoxScheme::Warning => write!(f, "checkbox-warning"),
-            CheckBoxScheme::Error => write!(f, "checkbox-error"),
-        }
-    }
-}
+use crate::color::{Color, ColorPrefix};
+use crate::form::{FieldMessages, FieldState, sets};
+use crate::size::{Size, SizePrefix};
 
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum CheckBoxSize {
-    #[default]
-    Default,
-    ExtraSmall,
-    Small,
-    Medium,
-    Large,
-}
+#[deprecated(since = "0.1.48", note = "use `Color` instead")]
+pub type CheckBoxScheme = Color;
 
-impl Display for CheckBoxSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            CheckBoxSize::Default => write!(f, ""),
-            CheckBoxSize::ExtraSmall => write!(f, "checkbox-xs"),
-            CheckBoxSize::Small => write!(f, "checkbox-sm"),
-            CheckBoxSize::Medium => write!(f, "checkbox-md"),
-            CheckBoxSize::Large => write!(f, "checkbox-lg"),
-        }
-    }
-}
+#[deprecated(since = "0.1.48", note = "use `Size` instead")]
+pub type CheckBoxSize = Size;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct CheckBoxProps {
     /// Label text displayed next to the checkbox
     pub label: String,
+    /// Name, value, validation errors and hint from the server
+    pub field: Option<FieldState>,
     #[props(default)]
-    pub checkbox_size: CheckBoxSize,
+    pub checkbox_size: Size,
     #[props(default)]
-    pub checkbox_scheme: CheckBoxScheme,
+    pub checkbox_scheme: Color,
     /// All standard HTML input attributes (name, value, checked, onchange, etc.)
     #[props(extends = input, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+impl ColorPrefix for CheckBoxProps {
+    const COLOR_PREFIX: &'static str = "checkbox";
+}
+
+impl SizePrefix for CheckBoxProps {
+    const SIZE_PREFIX: &'static str = "checkbox";
+}
+
 #[component]
 pub fn CheckBox(props: CheckBoxProps) -> Element {
-    let scheme = props.checkbox_scheme.to_string();
-    let size = props.checkbox_size.to_string();
+    let scheme = props.checkbox_scheme.class::<CheckBoxProps>();
+    let size = props.checkbox_size.class::<CheckBoxProps>();
+    let field_attributes = props
+        .field
+        .as_ref()
+        .map(|field| field.attributes_except(&props.attributes))
+        .unwrap_or_default();
+    let error = props
+        .field
+        .as_ref()
+        .map(FieldState::error_class::<CheckBoxProps>)
+        .unwrap_or_default();
+    let checked = props
+        .field
+        .as_ref()
+        .is_some_and(|field| field.is_checked() && !sets(&props.attributes, "checked"))
+        .then_some(true);
 
     rsx!(
         label { class: "flex items-center gap-2 cursor-pointer",
             input {
                 r#type: "checkbox",
-                class: "checkbox {scheme} {size}",
+                class: "checkbox {scheme} {size} {error}",
+                checked,
+                ..field_attributes,
                 ..props.attributes,
             }
             span { "{props.label}" }
+            FieldMessages { field: props.field }
         }
     )
 }
@@ -0,0 +1,106 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The semantic DaisyUI colors shared by every colored component.
+///
+/// `Display` and `FromStr` round-trip the bare color name (`"primary"`,
+/// `"error"`, ...) so a color can be stored in config or a database and
+/// handed to any component.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+    #[default]
+    Default,
+    Neutral,
+    Primary,
+    Secondary,
+    Accent,
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Color {
+    pub const ALL: [Color; 9] = [
+        Color::Default,
+        Color::Neutral,
+        Color::Primary,
+        Color::Secondary,
+        Color::Accent,
+        Color::Info,
+        Color::Success,
+        Color::Warning,
+        Color::Error,
+    ];
+
+    /// Spelling used by the old `AlertColor` and `ToolTipColor` enums.
+    #[deprecated(since = "0.1.48", note = "use `Color::Warning` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const Warn: Color = Color::Warning;
+
+    /// The DaisyUI modifier class for component `C`, e.g. `btn-primary`.
+    /// `Color::Default` produces an empty string.
+    pub fn class<C: ColorPrefix>(&self) -> String {
+        self.class_with_prefix(C::COLOR_PREFIX)
+    }
+
+    /// The modifier class for an arbitrary prefix, e.g. `bg` gives `bg-primary`.
+    pub fn class_with_prefix(&self, prefix: &str) -> String {
+        match self {
+            Color::Default => String::new(),
+            color => format!("{prefix}-{color}"),
+        }
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Color::Default => write!(f, ""),
+            Color::Neutral => write!(f, "neutral"),
+            Color::Primary => write!(f, "primary"),
+            Color::Secondary => write!(f, "secondary"),
+            Color::Accent => write!(f, "accent"),
+            Color::Info => write!(f, "info"),
+            Color::Success => write!(f, "success"),
+            Color::Warning => write!(f, "warning"),
+            Color::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseColorError(pub String);
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown color: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "" | "default" => Ok(Color::Default),
+            "neutral" => Ok(Color::Neutral),
+            "primary" => Ok(Color::Primary),
+            "secondary" => Ok(Color::Secondary),
+            "accent" => Ok(Color::Accent),
+            "info" => Ok(Color::Info),
+            "success" => Ok(Color::Success),
+            "warning" | "warn" => Ok(Color::Warning),
+            "error" => Ok(Color::Error),
+            _ => Err(ParseColorError(s.to_string())),
+        }
+    }
+}
+
+/// Implemented by each colored component to name the prefix DaisyUI uses for
+/// its color modifiers (`btn`, `badge`, `text`, ...).
+pub trait ColorPrefix {
+    const COLOR_PREFIX: &'static str;
+}
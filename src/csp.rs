@@ -0,0 +1,105 @@
+#![allow(non_snake_case)]
+//! Content-Security-Policy nonces.
+//!
+//! Generate a fresh random nonce for each request, pass it to `AppLayout` as
+//! `csp_nonce` (or wrap a fragment in [`CspProvider`]) and send
+//! [`CspNonce::header_value`] as the `Content-Security-Policy` header. Every
+//! `script`, `style` and stylesheet `link` this crate renders then carries the
+//! nonce.
+use dioxus::prelude::*;
+
+/// A per-request nonce, which must come from a cryptographically secure
+/// random source and be base64 encoded.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CspNonce(pub String);
+
+impl CspNonce {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// A strict `Content-Security-Policy` header value allowing scripts and
+    /// styles from the page's origin or carrying this nonce.
+    pub fn header_value(&self) -> String {
+        let nonce = &self.0;
+        format!(
+            "default-src 'self'; \
+             script-src 'self' 'nonce-{nonce}'; \
+             style-src 'self' 'nonce-{nonce}'; \
+             img-src 'self' data: https:; \
+             object-src 'none'; \
+             base-uri 'self'; \
+             form-action 'self'; \
+             frame-ancestors 'self'"
+        )
+    }
+}
+
+#[derive(Clone)]
+struct NonceContext(Option<CspNonce>);
+
+/// Makes `nonce` available to every component below it. Only needed for
+/// fragments, `AppLayout` provides its `csp_nonce` itself.
+pub fn provide_csp_nonce(nonce: Option<CspNonce>) {
+    use_hook(|| provide_context(NonceContext(nonce)));
+}
+
+/// The nonce provided by `AppLayout` or [`CspProvider`], if any.
+pub fn use_csp_nonce() -> Option<CspNonce> {
+    try_consume_context::<NonceContext>().and_then(|context| context.0)
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CspProviderProps {
+    pub nonce: CspNonce,
+    pub children: Element,
+}
+
+/// Provides a nonce to the components inside it, for fragments rendered
+/// without `AppLayout`.
+#[component]
+pub fn CspProvider(props: CspProviderProps) -> Element {
+    provide_csp_nonce(Some(props.nonce));
+    rsx!({ props.children })
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct InlineScriptProps {
+    /// The JavaScript, written as is
+    pub code: String,
+    #[props(default)]
+    pub module: bool,
+}
+
+/// An inline `script` carrying the page's nonce.
+#[component]
+pub fn InlineScript(props: InlineScriptProps) -> Element {
+    let nonce = use_csp_nonce().map(|nonce| nonce.0);
+    rsx!(
+        script {
+            "type": props.module.then_some("module"),
+            nonce,
+            dangerous_inner_html: "{props.code}",
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct InlineStyleProps {
+    /// The CSS, written as is
+    pub css: String,
+}
+
+/// An inline `style` carrying the page's nonce.
+#[component]
+pub fn InlineStyle(props: InlineStyleProps) -> Element {
+    let nonce = use_csp_nonce().map(|nonce| nonce.0);
+    rsx!(style {
+        nonce,
+        dangerous_inner_html: "{props.css}"
+    })
+}
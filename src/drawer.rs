@@ -1,55 +1,134 @@
 #![allow(non_snake_case)]
+use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::htmx::Hx;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DrawerSide {
+    Left,
+    #[default]
+    Right,
+}
+
+impl Display for DrawerSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DrawerSide::Left => write!(f, "modal-start"),
+            DrawerSide::Right => write!(f, "modal-end"),
+        }
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DrawerWidth {
+    Small,
+    #[default]
+    Medium,
+    Large,
+    ExtraLarge,
+    Full,
+}
+
+impl Display for DrawerWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DrawerWidth::Small => write!(f, "w-80"),
+            DrawerWidth::Medium => write!(f, "w-96"),
+            DrawerWidth::Large => write!(f, "w-[32rem]"),
+            DrawerWidth::ExtraLarge => write!(f, "w-[48rem]"),
+            DrawerWidth::Full => write!(f, "w-screen"),
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct DrawerProps {
+    /// The drawer id, used by the `popovertarget` of the buttons that open it
     pub trigger_id: String,
+    /// Title shown in the header and used as the accessible name
     pub label: String,
     pub children: Element,
+    /// Wraps the drawer in a form that POSTs here
     pub submit_action: Option<String>,
-    /// All standard HTML div attributes (id, style, etc.)
-    #[props(extends = div, extends = GlobalAttributes)]
+    /// Submit the form with htmx
+    pub hx: Option<Hx>,
+    #[props(default)]
+    pub side: DrawerSide,
+    #[props(default)]
+    pub width: DrawerWidth,
+    /// All standard HTML dialog attributes (id, style, etc.)
+    #[props(extends = dialog, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+/// A panel that slides in from the side of the page. Like `Modal` it's a
+/// popover, opened with `popovertarget` and closed with its ✕ button, the
+/// backdrop, Escape or a [`ModalClose`](crate::ModalClose) pointing at it,
+/// all without JavaScript.
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
+/// Button { popovertarget: "settings", "Settings" }
+/// Drawer { trigger_id: "settings", label: "Settings", submit_action: "/settings",
+///     DrawerBody { "..." }
+///     DrawerFooter {
+///         ModalClose { trigger_id: "settings", "Cancel" }
+///         Button { r#type: "submit", "Save" }
+///     }
+/// }
+/// # }}
+/// ```
 #[component]
 pub fn Drawer(props: DrawerProps) -> Element {
-    if let Some(action) = &props.submit_action {
-        rsx!(
-            form { action: "{action}", method: "post",
-                div {..props.attributes,
-                    div {
-                        class: "side-drawer flex flex-col",
-                        id: props.trigger_id,
-                        div { class: "drawer__overlay", tabindex: "-1" }
-                        div { class: "drawer__panel",
-                            header { class: "drawer__header",
-                                h4 { class: "drawer__title", "{props.label}" }
-                                a { href: "#", class: "drawer__close", "X" }
-                            }
-                            {props.children}
-                        }
+    let title_id = format!("{}-title", props.trigger_id);
+    let drawer = rsx!(
+        dialog {
+            class: "modal {props.side}",
+            id: "{props.trigger_id}",
+            popover: "auto",
+            "aria-labelledby": "{title_id}",
+            ..props.attributes,
+            div { class: "modal-box h-full max-h-screen max-w-full rounded-none p-0 flex flex-col {props.width}",
+                header { class: "flex items-center justify-between gap-2 p-4 border-b border-base-300",
+                    h4 { class: "font-bold text-lg", id: "{title_id}", "{props.label}" }
+                    button {
+                        r#type: "button",
+                        class: "btn btn-sm btn-circle btn-ghost",
+                        popovertarget: "{props.trigger_id}",
+                        popovertargetaction: "hide",
+                        "aria-label": "Close",
+                        "✕"
                     }
                 }
+                {props.children}
             }
-        )
-    } else {
-        rsx!(
-            div {..props.attributes,
-                div { class: "side-drawer flex flex-col", id: props.trigger_id,
-                    div { class: "drawer__overlay", tabindex: "-1" }
-                    div { class: "drawer__panel",
-                        header { class: "drawer__header",
-                            h4 { class: "drawer__title", "{props.label}" }
-                            a { href: "#", class: "drawer__close", "X" }
-                        }
-                        {props.children}
-                    }
-                }
+            button {
+                r#type: "button",
+                class: "modal-backdrop",
+                popovertarget: "{props.trigger_id}",
+                popovertargetaction: "hide",
+                "aria-label": "Close",
             }
-        )
-    }
+        }
+    );
+
+    rsx!(
+        if let Some(action) = &props.submit_action {
+            form {
+                action: "{action}",
+                method: "post",
+                ..props.hx.as_ref().map(|hx| hx.form_attributes(action)).unwrap_or_default(),
+                {drawer}
+            }
+        } else {
+            {drawer}
+        }
+    )
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -63,7 +142,11 @@ pub struct DrawerFooterProps {
 #[component]
 pub fn DrawerFooter(props: DrawerFooterProps) -> Element {
     rsx!(
-        div { class: "drawer__footer", ..props.attributes, {props.children} }
+        div {
+            class: "flex justify-end gap-2 p-4 border-t border-base-300",
+            ..props.attributes,
+            {props.children}
+        }
     )
 }
 
@@ -78,6 +161,6 @@ pub struct DrawerBodyProps {
 #[component]
 pub fn DrawerBody(props: DrawerBodyProps) -> Element {
     rsx!(
-        div { class: "drawer__body", ..props.attributes, {props.children} }
+        div { class: "flex-1 overflow-y-auto p-4", ..props.attributes, {props.children} }
     )
 }
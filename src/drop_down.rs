@@ -3,6 +3,8 @@ use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::htmx::{Hx, hx_attributes};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Direction {
     #[default]
@@ -72,6 +74,8 @@ pub fn DropDown(props: DropDownProps) -> Element {
 #[derive(Props, Clone, PartialEq)]
 pub struct DropDownLinkProps {
     pub children: Element,
+    /// Follow the link with htmx, swapping `#main-content` unless told otherwise
+    pub hx: Option<Hx>,
     /// All standard HTML anchor attributes (href, target, rel, onclick, etc.)
     #[props(extends = a, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
@@ -79,9 +83,22 @@ pub struct DropDownLinkProps {
 
 #[component]
 pub fn DropDownLink(props: DropDownLinkProps) -> Element {
+    let href =
+        props
+            .attributes
+            .iter()
+            .find_map(|attribute| match (attribute.name, &attribute.value) {
+                ("href", dioxus::core::AttributeValue::Text(href)) => Some(href.clone()),
+                _ => None,
+            });
+    let hx = match (&props.hx, href) {
+        (Some(hx), Some(href)) => hx.link_attributes(&href),
+        _ => hx_attributes(&props.hx),
+    };
+
     rsx!(
         li {
-            a { class: "dropdown-item", ..props.attributes, {props.children} }
+            a { class: "dropdown-item", ..hx, ..props.attributes, {props.children} }
         }
     )
 }
@@ -3,6 +3,10 @@ use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::color::{Color, ColorPrefix};
+use crate::form::{FieldMessages, FieldState};
+use crate::size::{Size, SizePrefix};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum FileInputStyle {
     #[default]
@@ -19,87 +23,63 @@ impl Display for FileInputStyle {
     }
 }
 
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum FileInputColor {
-    #[default]
-    Default,
-    Neutral,
-    Primary,
-    Secondary,
-    Accent,
-    Info,
-    Success,
-    Warning,
-    Error,
-}
+#[deprecated(since = "0.1.48", note = "use `Color` instead")]
+pub type FileInputColor = Color;
 
-impl Display for FileInputColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            FileInputColor::Default => write!(f, ""),
-            FileInputColor::Neutral => write!(f, "file-input-neutral"),
-            FileInputColor::Primary => write!(f, "file-input-primary"),
-            FileInputColor::Secondary => write!(f, "file-input-secondary"),
-            FileInputColor::Accent => write!(f, "file-input-accent"),
-            FileInputColor::Info => write!(f, "file-input-info"),
-            FileInputColor::Success => write!(f, "file-input-success"),
-            FileInputColor::Warning => write!(f, "file-input-warning"),
-            FileInputColor::Error => write!(f, "file-input-error"),
-        }
-    }
-}
-
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum FileInputSize {
-    #[default]
-    Default,
-    ExtraSmall,
-    Small,
-    Medium,
-    Large,
-}
-
-impl Display for FileInputSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            FileInputSize::Default => write!(f, ""),
-            FileInputSize::ExtraSmall => write!(f, "file-input-xs"),
-            FileInputSize::Small => write!(f, "file-input-sm"),
-            FileInputSize::Medium => write!(f, "file-input-md"),
-            FileInputSize::Large => write!(f, "file-input-lg"),
-        }
-    }
-}
+#[deprecated(since = "0.1.48", note = "use `Size` instead")]
+pub type FileInputSize = Size;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct FileInputProps {
     /// Label text displayed above the file input
     pub label: String,
+    /// Name, value, validation errors and hint from the server
+    pub field: Option<FieldState>,
     #[props(default)]
     pub file_input_style: FileInputStyle,
     #[props(default)]
-    pub file_input_color: FileInputColor,
+    pub file_input_color: Color,
     #[props(default)]
-    pub file_input_size: FileInputSize,
+    pub file_input_size: Size,
     /// All standard HTML input attributes (name, accept, multiple, etc.)
     #[props(extends = input, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+impl ColorPrefix for FileInputProps {
+    const COLOR_PREFIX: &'static str = "file-input";
+}
+
+impl SizePrefix for FileInputProps {
+    const SIZE_PREFIX: &'static str = "file-input";
+}
+
 #[component]
 pub fn FileInput(props: FileInputProps) -> Element {
     let style = props.file_input_style.to_string();
-    let color = props.file_input_color.to_string();
-    let size = props.file_input_size.to_string();
+    let color = props.file_input_color.class::<FileInputProps>();
+    let size = props.file_input_size.class::<FileInputProps>();
+    let field_attributes = props
+        .field
+        .as_ref()
+        .map(|field| field.attributes_except(&props.attributes))
+        .unwrap_or_default();
+    let error = props
+        .field
+        .as_ref()
+        .map(FieldState::error_class::<FileInputProps>)
+        .unwrap_or_default();
 
     rsx!(
         label { class: "flex flex-col gap-1",
             span { "{props.label}" }
             input {
                 r#type: "file",
-                class: "file-input {style} {color} {size}",
+                class: "file-input {style} {color} {size} {error}",
+                ..field_attributes,
                 ..props.attributes,
             }
+            FieldMessages { field: props.field }
         }
     )
 }
@@ -0,0 +1,88 @@
+//! Flash messages carried across a POST-redirect in a cookie.
+//!
+//! A handler sets the cookie with [`set_cookie_header`], and the next request
+//! reads it back with [`decode_flashes`], passes the messages to `AppLayout`
+//! as `flashes` and clears the cookie with [`clear_cookie_header`].
+use crate::color::Color;
+use crate::query::{decode, encode};
+
+/// Name of the cookie the helpers below read and write.
+pub const FLASH_COOKIE: &str = "flash";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Flash {
+    /// Shown as the color of the toast's `Alert`
+    pub level: Color,
+    pub message: String,
+}
+
+impl Flash {
+    pub fn new(level: Color, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            message: message.into(),
+        }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(Color::Info, message)
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(Color::Success, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Color::Warning, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Color::Error, message)
+    }
+}
+
+/// Encode flashes into a cookie-safe value such as `success=Saved%20it`.
+pub fn encode_flashes(flashes: &[Flash]) -> String {
+    flashes
+        .iter()
+        .map(|flash| format!("{}={}", flash.level, encode(&flash.message)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Decode a cookie value written by [`encode_flashes`]. Entries with an
+/// unknown level are skipped rather than failing the whole request.
+pub fn decode_flashes(value: &str) -> Vec<Flash> {
+    value
+        .split('&')
+        .filter(|entry| !entry.is_empty())
+        .filter_map(|entry| {
+            let (level, message) = entry.split_once('=')?;
+            let level = level.parse().ok()?;
+            Some(Flash::new(level, decode(message)))
+        })
+        .collect()
+}
+
+/// A `Set-Cookie` header value that stores `flashes` until the next request.
+pub fn set_cookie_header(flashes: &[Flash]) -> String {
+    format!(
+        "{FLASH_COOKIE}={}; Path=/; HttpOnly; SameSite=Lax",
+        encode_flashes(flashes)
+    )
+}
+
+/// A `Set-Cookie` header value that removes the flash cookie once shown.
+pub fn clear_cookie_header() -> String {
+    format!("{FLASH_COOKIE}=; Path=/; HttpOnly; SameSite=Lax; Max-Age=0")
+}
+
+/// Find the flash cookie in a `Cookie` request header and decode it.
+pub fn from_cookie_header(header: &str) -> Vec<Flash> {
+    header
+        .split(';')
+        .filter_map(|cookie| cookie.trim().split_once('='))
+        .find(|(name, _)| *name == FLASH_COOKIE)
+        .map(|(_, value)| decode_flashes(value))
+        .unwrap_or_default()
+}
@@ -0,0 +1,418 @@
+#![allow(non_snake_case)]
+use std::collections::BTreeMap;
+
+use dioxus::dioxus_core::AttributeValue;
+use dioxus::prelude::*;
+
+use crate::check_box::CheckBox;
+use crate::color::{Color, ColorPrefix};
+use crate::fieldset::Fieldset;
+use crate::input::Input;
+use crate::range::Range;
+use crate::select::{Select, SelectOption};
+use crate::text_area::TextArea;
+
+/// The server side state of one form field: its current value, any
+/// validation errors and an optional hint. Every form component accepts one
+/// as `field` so a failed POST can re-render the form with errors inline.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldState {
+    /// Used for the `name` attribute and, sanitised, the element ids
+    pub name: String,
+    pub value: Option<String>,
+    pub errors: Vec<String>,
+    pub hint: Option<String>,
+    pub required: bool,
+}
+
+impl FieldState {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn error(mut self, error: impl Into<String>) -> Self {
+        self.errors.push(error.into());
+        self
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    /// The `id` of the form control.
+    pub fn id(&self) -> String {
+        self.name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect()
+    }
+
+    pub fn hint_id(&self) -> String {
+        format!("{}-hint", self.id())
+    }
+
+    pub fn error_id(&self) -> String {
+        format!("{}-error", self.id())
+    }
+
+    /// The `aria-describedby` value pointing at the hint and errors, if any.
+    pub fn described_by(&self) -> Option<String> {
+        let ids: Vec<String> = [
+            self.hint.is_some().then(|| self.hint_id()),
+            self.is_invalid().then(|| self.error_id()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        (!ids.is_empty()).then(|| ids.join(" "))
+    }
+
+    /// `Some("true")` when there are errors, for `aria-invalid`.
+    pub fn aria_invalid(&self) -> Option<&'static str> {
+        self.is_invalid().then_some("true")
+    }
+
+    /// The `name`, `id`, `required` and ARIA attributes for the form control.
+    /// The value is left to each component since it's written differently
+    /// for inputs, textareas and checkboxes.
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let mut attributes = vec![
+            Attribute::new("name", self.name.clone(), None, false),
+            Attribute::new("id", self.id(), None, false),
+        ];
+        if self.required {
+            attributes.push(Attribute::new("required", true, None, false));
+        }
+        if let Some(invalid) = self.aria_invalid() {
+            attributes.push(Attribute::new("aria-invalid", invalid, None, false));
+        }
+        if let Some(described_by) = self.described_by() {
+            attributes.push(Attribute::new(
+                "aria-describedby",
+                described_by,
+                None,
+                false,
+            ));
+        }
+        attributes
+    }
+
+    /// [`attributes`](Self::attributes) without the ones `explicit` sets, so
+    /// attributes passed to a component override its field's.
+    pub fn attributes_except(&self, explicit: &[Attribute]) -> Vec<Attribute> {
+        self.attributes()
+            .into_iter()
+            .filter(|attribute| !sets(explicit, attribute.name))
+            .collect()
+    }
+
+    /// The value to write into the control, unless `explicit` sets its own.
+    pub fn value_except(&self, explicit: &[Attribute]) -> Option<String> {
+        self.value.clone().filter(|_| !sets(explicit, "value"))
+    }
+
+    /// The component's error color class (`input-error`, `select-error`, ...)
+    /// when the field is invalid.
+    pub fn error_class<C: ColorPrefix>(&self) -> String {
+        if self.is_invalid() {
+            Color::Error.class::<C>()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Checkbox style truthiness of `value`.
+    pub fn is_checked(&self) -> bool {
+        matches!(self.value.as_deref(), Some("on" | "true" | "1"))
+    }
+}
+
+/// Whether `attributes` include one called `name`.
+pub(crate) fn sets(attributes: &[Attribute], name: &str) -> bool {
+    attributes.iter().any(|attribute| attribute.name == name)
+}
+
+/// The text of the attribute called `name`, if there is one.
+pub(crate) fn text_attribute<'a>(attributes: &'a [Attribute], name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|attribute| attribute.name == name)
+        .and_then(|attribute| match &attribute.value {
+            AttributeValue::Text(text) => Some(text.as_str()),
+            _ => None,
+        })
+}
+
+/// Validation errors keyed by field name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormErrors(BTreeMap<String, Vec<String>>);
+
+impl FormErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, field: impl Into<String>, error: impl Into<String>) {
+        self.0.entry(field.into()).or_default().push(error.into());
+    }
+
+    pub fn get(&self, field: &str) -> &[String] {
+        self.0.get(field).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// A `FieldState` for `name` carrying its errors and the submitted value.
+    pub fn field(&self, name: impl Into<String>, value: impl Into<String>) -> FieldState {
+        let name = name.into();
+        FieldState {
+            errors: self.get(&name).to_vec(),
+            ..FieldState::new(name).value(value)
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.0
+            .iter()
+            .map(|(field, errors)| (field.as_str(), errors.as_slice()))
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for FormErrors {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut errors = FormErrors::new();
+        for (field, error) in iter {
+            errors.add(field, error);
+        }
+        errors
+    }
+}
+
+impl From<BTreeMap<String, Vec<String>>> for FormErrors {
+    fn from(map: BTreeMap<String, Vec<String>>) -> Self {
+        Self(map.into_iter().filter(|(_, e)| !e.is_empty()).collect())
+    }
+}
+
+impl From<std::collections::HashMap<String, Vec<String>>> for FormErrors {
+    fn from(map: std::collections::HashMap<String, Vec<String>>) -> Self {
+        Self(map.into_iter().filter(|(_, e)| !e.is_empty()).collect())
+    }
+}
+
+/// The hint and error messages below a form control, with the ids that its
+/// `aria-describedby` points at. Rendered as `span`s so they can sit inside
+/// the control's `label`.
+#[component]
+pub fn FieldMessages(field: Option<FieldState>) -> Element {
+    let Some(field) = field else {
+        return rsx!();
+    };
+
+    rsx!(
+        if let Some(hint) = &field.hint {
+            span { class: "label", id: field.hint_id(), "{hint}" }
+        }
+        if field.is_invalid() {
+            span {
+                class: "validator-hint text-error flex flex-col",
+                id: field.error_id(),
+                for error in &field.errors {
+                    span { "{error}" }
+                }
+            }
+        }
+    )
+}
+
+/// A form generated from a struct with `#[derive(DaisyForm)]`.
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// #[derive(Default, DaisyForm)]
+/// enum Plan {
+///     #[default]
+///     Free,
+///     Pro,
+/// }
+///
+/// #[derive(Default, DaisyForm)]
+/// #[daisy(legend = "Profile")]
+/// struct Profile {
+///     #[daisy(hint = "Shown to other members", required)]
+///     display_name: String,
+///     #[daisy(widget = "email")]
+///     email: String,
+///     #[daisy(widget = "textarea")]
+///     bio: Option<String>,
+///     newsletter: bool,
+///     plan: Plan,
+///     #[daisy(widget = "range", min = 0, max = 10)]
+///     volume: u8,
+///     #[daisy(skip)]
+///     id: i64,
+/// }
+///
+/// # fn app() -> Element {
+/// Profile::default().fieldset(&FormErrors::new())
+/// # }
+/// ```
+pub trait DaisyForm {
+    /// Legend of the generated `Fieldset`
+    const LEGEND: &'static str;
+
+    /// One labelled control per field, in declaration order.
+    fn form_controls(&self, errors: &FormErrors) -> Vec<Element>;
+
+    /// The controls wrapped in a `Fieldset`.
+    fn fieldset(&self, errors: &FormErrors) -> Element {
+        let controls = self.form_controls(errors);
+        rsx!(
+            Fieldset { legend: Self::LEGEND.to_string(),
+                for control in controls {
+                    {control}
+                }
+            }
+        )
+    }
+}
+
+/// A type that can be a field of a `DaisyForm` struct. Enums get an
+/// implementation rendering a `Select` from `#[derive(DaisyForm)]`.
+pub trait FormField {
+    /// The value as it's written into, and submitted from, the form.
+    fn form_value(&self) -> String;
+
+    /// The control used when the field has no `widget` attribute. It
+    /// depends only on the type, so an empty `Option` renders the same
+    /// control as a filled one.
+    fn widget() -> Widget {
+        Widget::Input("text")
+    }
+}
+
+impl FormField for String {
+    fn form_value(&self) -> String {
+        self.clone()
+    }
+}
+
+impl FormField for bool {
+    fn form_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn widget() -> Widget {
+        Widget::CheckBox
+    }
+}
+
+impl<T: FormField> FormField for Option<T> {
+    fn form_value(&self) -> String {
+        self.as_ref().map(T::form_value).unwrap_or_default()
+    }
+
+    fn widget() -> Widget {
+        T::widget()
+    }
+}
+
+macro_rules! number_fields {
+    ($step:expr => $($ty:ty),*) => {
+        $(
+            impl FormField for $ty {
+                fn form_value(&self) -> String {
+                    self.to_string()
+                }
+
+                fn widget() -> Widget {
+                    Widget::Number { step: $step }
+                }
+            }
+        )*
+    };
+}
+
+number_fields!(None::<&str> => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+number_fields!(Some("any") => f32, f64);
+
+/// The control a form field is rendered with.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Widget {
+    /// An `Input` with the given `type`
+    Input(&'static str),
+    /// A number `Input`, with `step: Some("any")` for fractions
+    Number {
+        step: Option<&'static str>,
+    },
+    TextArea,
+    /// A `CheckBox` that submits `true` when checked
+    CheckBox,
+    Range {
+        min: Option<f64>,
+        max: Option<f64>,
+        step: Option<f64>,
+    },
+    /// A `Select` from `(value, label)` pairs
+    Select(Vec<(String, String)>),
+}
+
+impl Widget {
+    pub fn render(&self, label: String, field: FieldState) -> Element {
+        match self {
+            Widget::Input(input_type) => rsx!(Input {
+                label,
+                field,
+                r#type: *input_type
+            }),
+            Widget::Number { step } => rsx!(Input {
+                label,
+                field,
+                r#type: "number",
+                step: *step
+            }),
+            Widget::TextArea => rsx!(TextArea { label, field }),
+            Widget::CheckBox => rsx!(CheckBox {
+                label,
+                field,
+                value: "true"
+            }),
+            Widget::Range { min, max, step } => rsx!(Range {
+                label,
+                field,
+                min: min.map(|min| min.to_string()),
+                max: max.map(|max| max.to_string()),
+                step: step.map(|step| step.to_string())
+            }),
+            Widget::Select(choices) => rsx!(
+                Select { label, field,
+                    for (value, text) in choices {
+                        SelectOption { value: "{value}", "{text}" }
+                    }
+                }
+            ),
+        }
+    }
+}
@@ -0,0 +1,268 @@
+#![allow(non_snake_case)]
+//! Typed htmx attributes.
+//!
+//! Components that navigate or submit take an `hx: Option<Hx>`, nav items
+//! take one as their `navigation`. Links and forms fill in the request from
+//! their own `href` or `action`, so `Hx::new()` on its own is enough to make
+//! them swap `#main-content`.
+//!
+//! ```rust
+//! # use daisy_rsx::*;
+//! # use dioxus::prelude::*;
+//! # fn app() -> Element { rsx! {
+//! Button {
+//!     hx: Hx::post("/jobs/42/retry").target("#job-42").swap(HxSwap::OuterHtml),
+//!     "Retry"
+//! }
+//! NavItem {
+//!     href: "/projects",
+//!     icon: "/icons/projects.svg",
+//!     title: "Projects",
+//!     navigation: Hx::new().push_url(),
+//! }
+//! # }}
+//! ```
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+/// Id of the element `AppLayout` renders page content in. Requests from
+/// `Hx::new()` links swap it by default.
+pub const MAIN_CONTENT_ID: &str = "main-content";
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HxMethod {
+    #[default]
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl HxMethod {
+    /// `hx-get`, `hx-post`...
+    pub fn attribute_name(&self) -> &'static str {
+        match self {
+            HxMethod::Get => "hx-get",
+            HxMethod::Post => "hx-post",
+            HxMethod::Put => "hx-put",
+            HxMethod::Patch => "hx-patch",
+            HxMethod::Delete => "hx-delete",
+        }
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HxSwap {
+    #[default]
+    InnerHtml,
+    OuterHtml,
+    BeforeBegin,
+    AfterBegin,
+    BeforeEnd,
+    AfterEnd,
+    Delete,
+    None,
+}
+
+impl Display for HxSwap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HxSwap::InnerHtml => write!(f, "innerHTML"),
+            HxSwap::OuterHtml => write!(f, "outerHTML"),
+            HxSwap::BeforeBegin => write!(f, "beforebegin"),
+            HxSwap::AfterBegin => write!(f, "afterbegin"),
+            HxSwap::BeforeEnd => write!(f, "beforeend"),
+            HxSwap::AfterEnd => write!(f, "afterend"),
+            HxSwap::Delete => write!(f, "delete"),
+            HxSwap::None => write!(f, "none"),
+        }
+    }
+}
+
+/// The htmx attributes for one element.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Hx {
+    /// The request to make. Links and forms use their `href` or `action`
+    /// when it's `None`.
+    pub request: Option<(HxMethod, String)>,
+    pub target: Option<String>,
+    pub swap: Option<HxSwap>,
+    pub push_url: Option<String>,
+    pub indicator: Option<String>,
+}
+
+impl Hx {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn request(method: HxMethod, url: impl Into<String>) -> Self {
+        Self {
+            request: Some((method, url.into())),
+            ..Self::default()
+        }
+    }
+
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::request(HxMethod::Get, url)
+    }
+
+    pub fn post(url: impl Into<String>) -> Self {
+        Self::request(HxMethod::Post, url)
+    }
+
+    pub fn put(url: impl Into<String>) -> Self {
+        Self::request(HxMethod::Put, url)
+    }
+
+    pub fn patch(url: impl Into<String>) -> Self {
+        Self::request(HxMethod::Patch, url)
+    }
+
+    pub fn delete(url: impl Into<String>) -> Self {
+        Self::request(HxMethod::Delete, url)
+    }
+
+    /// CSS selector of the element to swap
+    pub fn target(mut self, selector: impl Into<String>) -> Self {
+        self.target = Some(selector.into());
+        self
+    }
+
+    pub fn swap(mut self, swap: HxSwap) -> Self {
+        self.swap = Some(swap);
+        self
+    }
+
+    /// Push the request URL onto the browser history.
+    pub fn push_url(mut self) -> Self {
+        self.push_url = Some("true".to_string());
+        self
+    }
+
+    /// Push `url` onto the browser history instead of the request URL.
+    pub fn push_url_to(mut self, url: impl Into<String>) -> Self {
+        self.push_url = Some(url.into());
+        self
+    }
+
+    /// CSS selector of the element shown while the request is in flight
+    pub fn indicator(mut self, selector: impl Into<String>) -> Self {
+        self.indicator = Some(selector.into());
+        self
+    }
+
+    /// The attributes as set, for elements with no URL of their own.
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let mut attributes = Vec::new();
+        if let Some((method, url)) = &self.request {
+            attributes.push(attribute(method.attribute_name(), url));
+        }
+        if let Some(target) = &self.target {
+            attributes.push(attribute("hx-target", target));
+        }
+        if let Some(swap) = &self.swap {
+            attributes.push(attribute("hx-swap", &swap.to_string()));
+        }
+        if let Some(push_url) = &self.push_url {
+            attributes.push(attribute("hx-push-url", push_url));
+        }
+        if let Some(indicator) = &self.indicator {
+            attributes.push(attribute("hx-indicator", indicator));
+        }
+        attributes
+    }
+
+    /// The attributes for a link, getting `href` into `#main-content`
+    /// unless told otherwise.
+    pub fn link_attributes(&self, href: &str) -> Vec<Attribute> {
+        self.with_defaults(HxMethod::Get, href).attributes()
+    }
+
+    /// The attributes for a form, posting to `action` unless told otherwise.
+    pub fn form_attributes(&self, action: &str) -> Vec<Attribute> {
+        self.with_defaults(HxMethod::Post, action).attributes()
+    }
+
+    fn with_defaults(&self, method: HxMethod, url: &str) -> Self {
+        let mut hx = self.clone();
+        if hx.request.is_none() {
+            hx.request = Some((method, url.to_string()));
+            if hx.target.is_none() && method == HxMethod::Get {
+                hx.target = Some(format!("#{MAIN_CONTENT_ID}"));
+            }
+        }
+        hx
+    }
+}
+
+/// Attributes for htmx's `sse` extension: connect to `url` and swap the
+/// element's content with the HTML of each `event`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sse {
+    pub url: String,
+    pub event: String,
+    /// Event that closes the connection, such as when a job has finished
+    pub close: Option<String>,
+}
+
+impl Sse {
+    pub fn new(url: impl Into<String>, event: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            event: event.into(),
+            close: None,
+        }
+    }
+
+    pub fn close(mut self, event: impl Into<String>) -> Self {
+        self.close = Some(event.into());
+        self
+    }
+
+    pub fn attributes(&self) -> Vec<Attribute> {
+        let mut attributes = vec![
+            attribute("hx-ext", "sse"),
+            attribute("sse-connect", &self.url),
+            attribute("sse-swap", &self.event),
+        ];
+        if let Some(close) = &self.close {
+            attributes.push(attribute("sse-close", close));
+        }
+        attributes
+    }
+}
+
+fn attribute(name: &'static str, value: &str) -> Attribute {
+    Attribute::new(name, value.to_string(), None, false)
+}
+
+/// The htmx attributes for `hx` on an element with no URL of its own.
+pub(crate) fn hx_attributes(hx: &Option<Hx>) -> Vec<Attribute> {
+    hx.as_ref().map(Hx::attributes).unwrap_or_default()
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct OobSwapProps {
+    /// Id of the element on the page this replaces
+    pub id: String,
+    /// How to swap it, replacing the whole element by default
+    pub swap: Option<HxSwap>,
+    pub children: Element,
+}
+
+/// Content swapped in out of band, alongside the main response, such as a
+/// counter in the sidebar after adding an item.
+#[component]
+pub fn OobSwap(props: OobSwapProps) -> Element {
+    let swap = props
+        .swap
+        .map(|swap| swap.to_string())
+        .unwrap_or_else(|| "true".to_string());
+
+    rsx!(
+        div { id: "{props.id}", "hx-swap-oob": "{swap}", {props.children} }
+    )
+}
@@ -1,64 +1,80 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum InputSize {
-    #[default]
-    Default,
-    Small,
-    ExtraSmall,
-    Large,
-    Medium,
-}
+use crate::color::ColorPrefix;
+use crate::form::{FieldMessages, FieldState};
+use crate::size::{Size, SizePrefix};
 
-impl Display for InputSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            InputSize::Default => write!(f, ""),
-            InputSize::ExtraSmall => write!(f, "input-xs"),
-            InputSize::Small => write!(f, "input-sm"),
-            InputSize::Medium => write!(f, "input-md"),
-            InputSize::Large => write!(f, "input-lg"),
-        }
-    }
-}
+#[deprecated(since = "0.1.48", note = "use `Size` instead")]
+pub type InputSize = Size;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct InputProps {
     /// Label text displayed above the input
     pub label: String,
+    /// Name, value, validation errors and hint from the server
+    pub field: Option<FieldState>,
     /// DaisyUI size modifier
     #[props(default)]
-    pub input_size: InputSize,
+    pub input_size: Size,
     /// All standard HTML input attributes (name, type, value, placeholder, oninput, etc.)
     #[props(extends = input, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+impl ColorPrefix for InputProps {
+    const COLOR_PREFIX: &'static str = "input";
+}
+
+impl SizePrefix for InputProps {
+    const SIZE_PREFIX: &'static str = "input";
+}
+
 /// A labeled input component with DaisyUI styling.
 /// The label wraps the input for accessibility.
 ///
 /// # Example
 /// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
 /// Input {
 ///     label: "Email",
-///     input_size: InputSize::Small,
+///     input_size: Size::Small,
 ///     name: "email",
 ///     r#type: "email",
 ///     placeholder: "you@example.com",
-///     oninput: move |e| { /* handle input */ },
 /// }
+/// # }}
 /// ```
 #[component]
 pub fn Input(props: InputProps) -> Element {
-    let size_class = props.input_size.to_string();
+    let size_class = props.input_size.class::<InputProps>();
+    let field_attributes = props
+        .field
+        .as_ref()
+        .map(|field| field.attributes_except(&props.attributes))
+        .unwrap_or_default();
+    let error = props
+        .field
+        .as_ref()
+        .map(FieldState::error_class::<InputProps>)
+        .unwrap_or_default();
+    let value = props
+        .field
+        .as_ref()
+        .and_then(|field| field.value_except(&props.attributes));
 
     rsx!(
         label { class: "flex flex-col gap-1",
             span { "{props.label}" }
-            input { class: "input input-bordered {size_class}", ..props.attributes }
+            input {
+                class: "input input-bordered {size_class} {error}",
+                value,
+                ..field_attributes,
+                ..props.attributes,
+            }
+            FieldMessages { field: props.field }
         }
     )
 }
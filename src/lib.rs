@@ -2,57 +2,120 @@ pub mod accordian;
 pub mod alert;
 pub mod app_layout;
 pub mod avatar;
+#[cfg(feature = "axum")]
+pub mod axum;
 pub mod badge;
 pub mod blank_slate;
 pub mod breadcrumb;
 pub mod button;
 pub mod card;
 pub mod check_box;
+pub mod color;
+pub mod csp;
 pub mod drawer;
 pub mod drop_down;
 pub mod fieldset;
 pub mod file_input;
+pub mod flash;
+pub mod form;
+pub mod htmx;
 pub mod input;
 pub mod loading;
 pub mod marketing;
 pub mod modal;
 pub mod nav_item;
+pub mod nav_tree;
+pub mod page_meta;
 pub mod pagination;
+pub mod progress;
+mod query;
+pub mod radio;
 pub mod range;
+pub mod rating;
 pub mod relative_time;
 pub mod select;
+pub mod size;
+pub mod snapshot;
+pub mod ssr;
+pub mod stats;
+pub mod steps;
 pub mod tab_container;
+pub mod table;
 pub mod text_area;
+pub mod theme;
 pub mod time_line;
 pub mod timeline;
+pub mod toast;
+pub mod toggle;
 pub mod tooltip;
 
 pub use accordian::Accordian;
-pub use alert::{Alert, AlertColor};
-pub use app_layout::AppLayout;
+pub use alert::Alert;
+pub use app_layout::{AppLayout, SidebarWidth};
 pub use avatar::{Avatar, AvatarSize, AvatarType};
-pub use badge::{Badge, BadgeColor, BadgeSize, BadgeStyle};
+pub use badge::{Badge, BadgeStyle};
 pub use blank_slate::BlankSlate;
 pub use breadcrumb::{Breadcrumb, BreadcrumbItem};
-pub use button::{Button, ButtonScheme, ButtonShape, ButtonSize, ButtonStyle};
+pub use button::{Button, ButtonShape, ButtonStyle};
 pub use card::{Card, CardBody, CardHeader};
-pub use check_box::{CheckBox, CheckBoxScheme, CheckBoxSize};
-pub use drawer::{Drawer, DrawerBody, DrawerFooter};
+pub use check_box::CheckBox;
+pub use color::{Color, ColorPrefix, ParseColorError};
+pub use csp::{CspNonce, CspProvider, InlineScript, InlineStyle, use_csp_nonce};
+pub use daisy_rsx_macros::DaisyForm;
+pub use drawer::{Drawer, DrawerBody, DrawerFooter, DrawerSide, DrawerWidth};
 pub use drop_down::{Direction, DropDown, DropDownLink};
 pub use fieldset::Fieldset;
-pub use file_input::{FileInput, FileInputColor, FileInputSize, FileInputStyle};
-pub use input::{Input, InputSize};
-pub use loading::{Loading, LoadingColor, LoadingSize, LoadingType};
-pub use modal::{Modal, ModalAction, ModalBody};
-pub use nav_item::{NavGroup, NavItem, NavSubGroup, NavSubItem};
-pub use pagination::Pagination;
-pub use range::{Range, RangeColor, RangeSize};
+pub use file_input::{FileInput, FileInputStyle};
+pub use flash::Flash;
+pub use form::{DaisyForm, FieldMessages, FieldState, FormErrors, FormField, Widget};
+pub use htmx::{Hx, HxMethod, HxSwap, OobSwap, Sse};
+pub use input::Input;
+pub use loading::{Loading, LoadingType};
+pub use modal::{ConfirmModal, Modal, ModalAction, ModalBody, ModalClose, ModalPlacement};
+pub use nav_item::{NavGroup, NavIcon, NavItem, NavSubGroup, NavSubItem, Navigation};
+pub use nav_tree::{NavPermissions, NavTree, NavTreeGroup, NavTreeItem, SidebarNav};
+pub use page_meta::{OpenGraph, PageMeta, PageMetaTags, Preload, Script, ScriptLoading};
+pub use pagination::{
+    CursorPagination, NumberedPagination, PageItem, PageUrl, Pagination, page_window,
+};
+pub use progress::{CssLength, Progress, RadialProgress};
+pub use radio::{Radio, RadioGroup};
+pub use range::Range;
+pub use rating::{Rating, RatingMask};
 pub use relative_time::{RelativeTime, RelativeTimeFormat};
-pub use select::{Select, SelectOption, SelectSize};
+pub use select::{Select, SelectOption};
+pub use size::{ParseSizeError, Size, SizePrefix};
+pub use stats::{Metric, Sparkline, Stat, Stats, StatsDirection, Trend, sparkline_points};
+pub use steps::{
+    ParseWizardActionError, Step, Steps, StepsDirection, WIZARD_ACTION_FIELD, WIZARD_STEP_FIELD,
+    Wizard, WizardAction, WizardStep,
+};
 pub use tab_container::{TabContainer, TabPanel};
-pub use text_area::{TextArea, TextAreaSize};
+pub use table::{
+    Column, DataTable, ParseSortDirectionError, Sort, SortDirection, Table, TableBody, TableCell,
+    TableHead, TableRow,
+};
+pub use text_area::TextArea;
+pub use theme::{Theme, ThemeController, ThemeControllerStyle};
 pub use time_line::{TimeLine, TimeLineBadge, TimeLineBody};
 pub use timeline::{
     Timeline, TimelineDirection, TimelineEnd, TimelineItem, TimelineMiddle, TimelineStart,
 };
-pub use tooltip::{ToolTip, ToolTipColor};
+pub use toast::{Flashes, Toast, ToastHorizontal, ToastVertical};
+pub use toggle::Toggle;
+pub use tooltip::ToolTip;
+
+// Per-component color enums replaced by `Color`, kept for one release.
+#[allow(deprecated)]
+pub use {
+    alert::AlertColor, badge::BadgeColor, button::ButtonScheme, check_box::CheckBoxScheme,
+    file_input::FileInputColor, loading::LoadingColor, range::RangeColor, tooltip::ToolTipColor,
+};
+
+// Per-component size enums replaced by `Size`, kept for one release.
+#[allow(deprecated)]
+pub use {
+    badge::BadgeSize, button::ButtonSize, check_box::CheckBoxSize, file_input::FileInputSize,
+    input::InputSize, loading::LoadingSize, range::RangeSize, select::SelectSize,
+    text_area::TextAreaSize,
+};
@@ -3,6 +3,9 @@ use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::color::{Color, ColorPrefix};
+use crate::size::{Size, SizePrefix};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum LoadingType {
     #[default]
@@ -27,86 +30,53 @@ impl Display for LoadingType {
     }
 }
 
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum LoadingSize {
-    ExtraSmall,
-    Small,
-    #[default]
-    Medium,
-    Large,
-    ExtraLarge,
-}
-
-impl Display for LoadingSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            LoadingSize::ExtraSmall => write!(f, "loading-xs"),
-            LoadingSize::Small => write!(f, "loading-sm"),
-            LoadingSize::Medium => write!(f, "loading-md"),
-            LoadingSize::Large => write!(f, "loading-lg"),
-            LoadingSize::ExtraLarge => write!(f, "loading-xl"),
-        }
-    }
-}
-
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum LoadingColor {
-    #[default]
-    Default,
-    Primary,
-    Secondary,
-    Accent,
-    Neutral,
-    Info,
-    Success,
-    Warning,
-    Error,
-}
+#[deprecated(since = "0.1.48", note = "use `Color` instead")]
+pub type LoadingColor = Color;
 
-impl Display for LoadingColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            LoadingColor::Default => write!(f, ""),
-            LoadingColor::Primary => write!(f, "text-primary"),
-            LoadingColor::Secondary => write!(f, "text-secondary"),
-            LoadingColor::Accent => write!(f, "text-accent"),
-            LoadingColor::Neutral => write!(f, "text-neutral"),
-            LoadingColor::Info => write!(f, "text-info"),
-            LoadingColor::Success => write!(f, "text-success"),
-            LoadingColor::Warning => write!(f, "text-warning"),
-            LoadingColor::Error => write!(f, "text-error"),
-        }
-    }
-}
+#[deprecated(since = "0.1.48", note = "use `Size` instead")]
+pub type LoadingSize = Size;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct LoadingProps {
     #[props(default)]
     pub loading_type: LoadingType,
+    /// Defaults to `Size::Medium`, matching the class earlier releases rendered.
+    #[props(default = Size::Medium)]
+    pub loading_size: Size,
     #[props(default)]
-    pub loading_size: LoadingSize,
-    #[props(default)]
-    pub loading_color: LoadingColor,
+    pub loading_color: Color,
     /// All standard HTML span attributes (id, style, etc.)
     #[props(extends = span, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+impl ColorPrefix for LoadingProps {
+    const COLOR_PREFIX: &'static str = "text";
+}
+
+impl SizePrefix for LoadingProps {
+    const SIZE_PREFIX: &'static str = "loading";
+}
+
 /// A loading spinner component with DaisyUI styling.
 ///
 /// # Example
 /// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
 /// Loading {
 ///     loading_type: LoadingType::Spinner,
-///     loading_size: LoadingSize::Large,
-///     loading_color: LoadingColor::Primary,
+///     loading_size: Size::Large,
+///     loading_color: Color::Primary,
 /// }
+/// # }}
 /// ```
 #[component]
 pub fn Loading(props: LoadingProps) -> Element {
     let loading_type = props.loading_type.to_string();
-    let size = props.loading_size.to_string();
-    let color = props.loading_color.to_string();
+    let size = props.loading_size.class::<LoadingProps>();
+    let color = props.loading_color.class::<LoadingProps>();
 
     rsx!(span {
         class: "loading {loading_type} {size} {color}",
@@ -1,38 +1,123 @@
 #![allow(non_snake_case)]
+use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::button::{Button, ButtonStyle};
+use crate::color::Color;
+use crate::htmx::Hx;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ModalPlacement {
+    #[default]
+    Middle,
+    Top,
+    Bottom,
+    /// A bottom sheet on small screens, centered from `sm` up
+    Responsive,
+}
+
+impl Display for ModalPlacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModalPlacement::Middle => write!(f, "modal-middle"),
+            ModalPlacement::Top => write!(f, "modal-top"),
+            ModalPlacement::Bottom => write!(f, "modal-bottom"),
+            ModalPlacement::Responsive => write!(f, "modal-bottom sm:modal-middle"),
+        }
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ModalProps {
+    /// The dialog id, used by the `popovertarget` of the buttons that open it
     pub trigger_id: String,
     pub children: Element,
+    /// Wraps the dialog in a form that POSTs here
     pub submit_action: Option<String>,
+    /// Submit the form with htmx
+    pub hx: Option<Hx>,
+    /// Heading shown at the top of the box and used as the accessible name
+    pub title: Option<String>,
+    #[props(default)]
+    pub placement: ModalPlacement,
+    /// Show the ✕ button in the corner of the box
+    #[props(default = true)]
+    pub close_button: bool,
+    /// Dim the page behind the box and close when it's clicked
+    #[props(default = true)]
+    pub backdrop: bool,
     /// All standard HTML dialog attributes (id, style, etc.)
     #[props(extends = dialog, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+/// A dialog opened with `popovertarget` and closed with the built-in close
+/// button, the backdrop or any button using [`ModalClose`].
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
+/// Button { popovertarget: "invite", "Invite" }
+/// Modal { trigger_id: "invite", title: "Invite a member",
+///     ModalBody { "They'll get an email with a link." }
+///     ModalAction {
+///         ModalClose { trigger_id: "invite", "Cancel" }
+///     }
+/// }
+/// # }}
+/// ```
 #[component]
 pub fn Modal(props: ModalProps) -> Element {
+    let title_id = format!("{}-title", props.trigger_id);
+    let labelled_by = props.title.as_ref().map(|_| title_id.clone());
+    let dialog = rsx!(
+        dialog {
+            class: "modal {props.placement}",
+            id: "{props.trigger_id}",
+            popover: "auto",
+            "aria-labelledby": labelled_by,
+            ..props.attributes,
+            div { class: "modal-box",
+                if props.close_button {
+                    button {
+                        r#type: "button",
+                        class: "btn btn-sm btn-circle btn-ghost absolute right-2 top-2",
+                        popovertarget: "{props.trigger_id}",
+                        popovertargetaction: "hide",
+                        "aria-label": "Close",
+                        "✕"
+                    }
+                }
+                if let Some(title) = &props.title {
+                    h3 { class: "font-bold text-lg", id: "{title_id}", "{title}" }
+                }
+                {props.children}
+            }
+            if props.backdrop {
+                button {
+                    r#type: "button",
+                    class: "modal-backdrop",
+                    popovertarget: "{props.trigger_id}",
+                    popovertargetaction: "hide",
+                    "aria-label": "Close",
+                }
+            }
+        }
+    );
+
     rsx!(
         if let Some(action) = &props.submit_action {
-            form { action: "{action}", method: "post",
-                dialog {
-                    class: "modal",
-                    id: "{props.trigger_id}",
-                    popover: "auto",
-                    ..props.attributes,
-                    {props.children}
-                }
+            form {
+                action: "{action}",
+                method: "post",
+                ..props.hx.as_ref().map(|hx| hx.form_attributes(action)).unwrap_or_default(),
+                {dialog}
             }
         } else {
-            dialog {
-                class: "modal",
-                id: "{props.trigger_id}",
-                popover: "auto",
-                ..props.attributes,
-                {props.children}
-            }
+            {dialog}
         }
     )
 }
@@ -45,10 +130,11 @@ pub struct ModalBodyProps {
     pub attributes: Vec<Attribute>,
 }
 
+/// The main content of a `Modal`, below the title.
 #[component]
 pub fn ModalBody(props: ModalBodyProps) -> Element {
     rsx!(
-        div { class: "modal-box", ..props.attributes, {props.children} }
+        div { class: "py-4", ..props.attributes, {props.children} }
     )
 }
 
@@ -66,3 +152,97 @@ pub fn ModalAction(props: ModalActionProps) -> Element {
         div { class: "modal-action", ..props.attributes, {props.children} }
     )
 }
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ModalCloseProps {
+    /// The `trigger_id` of the modal to close
+    pub trigger_id: String,
+    pub children: Element,
+    #[props(default = ButtonStyle::Ghost)]
+    pub button_style: ButtonStyle,
+    /// All standard HTML button attributes (disabled, onclick, etc.)
+    #[props(extends = button, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// A button that closes a modal without submitting its form.
+#[component]
+pub fn ModalClose(props: ModalCloseProps) -> Element {
+    rsx!(
+        button {
+            r#type: "button",
+            class: "btn {props.button_style}",
+            popovertarget: "{props.trigger_id}",
+            popovertargetaction: "hide",
+            ..props.attributes,
+            {props.children}
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ConfirmModalProps {
+    pub trigger_id: String,
+    pub title: String,
+    /// What will happen if the user confirms
+    pub message: String,
+    /// Where the confirmation is POSTed
+    pub submit_action: String,
+    /// Hidden `(name, value)` inputs sent with the confirmation, such as an id
+    #[props(default)]
+    pub hidden_fields: Vec<(String, String)>,
+    #[props(into, default = "Confirm".to_string())]
+    pub confirm_label: String,
+    #[props(into, default = "Cancel".to_string())]
+    pub cancel_label: String,
+    /// Color of the confirm button, `Error` by default for destructive actions
+    #[props(default = Color::Error)]
+    pub confirm_color: Color,
+    #[props(default)]
+    pub placement: ModalPlacement,
+    /// Submit the confirmation with htmx
+    pub hx: Option<Hx>,
+}
+
+/// A `Modal` asking the user to confirm an action, such as a delete, before
+/// it's POSTed to `submit_action`.
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
+/// Button { popovertarget: "delete-team", button_scheme: Color::Error, "Delete" }
+/// ConfirmModal {
+///     trigger_id: "delete-team",
+///     title: "Delete team?",
+///     message: "This removes every project in the team.",
+///     submit_action: "/teams/delete",
+///     hidden_fields: vec![("id".to_string(), "42".to_string())],
+///     confirm_label: "Delete",
+/// }
+/// # }}
+/// ```
+#[component]
+pub fn ConfirmModal(props: ConfirmModalProps) -> Element {
+    rsx!(
+        Modal {
+            trigger_id: props.trigger_id.clone(),
+            title: props.title,
+            submit_action: props.submit_action,
+            hx: props.hx,
+            placement: props.placement,
+            role: "alertdialog",
+            ModalBody {
+                p { "{props.message}" }
+                for (name, value) in &props.hidden_fields {
+                    input { r#type: "hidden", name: "{name}", value: "{value}" }
+                }
+            }
+            ModalAction {
+                ModalClose { trigger_id: props.trigger_id, "{props.cancel_label}" }
+                Button { r#type: "submit", button_scheme: props.confirm_color, "{props.confirm_label}" }
+            }
+        }
+    )
+}
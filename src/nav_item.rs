@@ -1,29 +1,153 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
 
+use crate::htmx::{Hx, MAIN_CONTENT_ID};
+
+/// How a nav link loads its page.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Navigation {
+    /// A normal link
+    Link,
+    /// Load into the Turbo frame with this id
+    TurboFrame(String),
+    /// `hx-boost` the link, so htmx swaps the page body
+    HxBoost,
+    /// Load with these htmx attributes, `#main-content` by default
+    Htmx(Hx),
+    /// Add these attributes to the link
+    Attributes(Vec<Attribute>),
+}
+
+impl Default for Navigation {
+    /// The `main-content` Turbo frame
+    fn default() -> Self {
+        Navigation::TurboFrame(MAIN_CONTENT_ID.to_string())
+    }
+}
+
+impl From<Hx> for Navigation {
+    fn from(hx: Hx) -> Self {
+        Navigation::Htmx(hx)
+    }
+}
+
+impl Navigation {
+    /// The attributes for a link to `href`.
+    pub fn attributes(&self, href: &str) -> Vec<Attribute> {
+        match self {
+            Navigation::Link => Vec::new(),
+            Navigation::TurboFrame(frame) => vec![Attribute::new(
+                "data-turbo-frame",
+                frame.clone(),
+                None,
+                false,
+            )],
+            Navigation::HxBoost => vec![Attribute::new("hx-boost", "true", None, false)],
+            Navigation::Htmx(hx) => hx.link_attributes(href),
+            Navigation::Attributes(attributes) => attributes.clone(),
+        }
+    }
+}
+
+/// An image URL or inline element, such as an SVG, shown before the title.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NavIcon {
+    Image(String),
+    Element(Element),
+}
+
+impl From<&str> for NavIcon {
+    fn from(src: &str) -> Self {
+        NavIcon::Image(src.to_string())
+    }
+}
+
+impl From<String> for NavIcon {
+    fn from(src: String) -> Self {
+        NavIcon::Image(src)
+    }
+}
+
+impl From<Element> for NavIcon {
+    fn from(element: Element) -> Self {
+        NavIcon::Element(element)
+    }
+}
+
+fn is_selected(id: &Option<String>, selected_item_id: &Option<String>) -> bool {
+    matches!((id, selected_item_id), (Some(id), Some(selected_id)) if id == selected_id)
+}
+
+pub(crate) fn nav_icon(icon: &Option<NavIcon>) -> Element {
+    match icon {
+        Some(NavIcon::Image(src)) => rsx!(img {
+            width: "16",
+            height: "16",
+            src: "{src}",
+            alt: ""
+        }),
+        Some(NavIcon::Element(element)) => element.clone(),
+        None => rsx!(),
+    }
+}
+
+pub(crate) fn nav_badge(badge: Option<usize>) -> Element {
+    rsx!(
+        if let Some(count) = badge {
+            span { class: "badge badge-sm", "{count}" }
+        }
+    )
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct NavItemProps {
     href: String,
-    icon: String,
+    #[props(into)]
+    icon: NavIcon,
     title: String,
     selected_item_id: Option<String>,
     id: Option<String>,
+    #[props(into, default)]
+    navigation: Navigation,
+    /// A count shown after the title, such as unread messages
+    badge: Option<usize>,
 }
 
+/// A sidebar link. The one whose `id` matches `selected_item_id` is shown as
+/// active and marked as the current page.
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
+/// NavItem {
+///     id: "inbox",
+///     selected_item_id: "inbox",
+///     href: "/inbox",
+///     icon: rsx! { svg { class: "size-4" } },
+///     title: "Inbox",
+///     badge: 3,
+///     navigation: Hx::new().push_url(),
+/// }
+/// # }}
+/// ```
 #[component]
 pub fn NavItem(props: NavItemProps) -> Element {
-    let class = match (&props.id, &props.selected_item_id) {
-        (Some(id), Some(selected_id)) if id == selected_id => "active",
-        _ => "",
-    };
+    let selected = is_selected(&props.id, &props.selected_item_id);
+    let class = if selected { "active" } else { "" };
+    let navigation = props.navigation.attributes(&props.href);
+
     rsx!(
         li { role: "listitem",
             a {
                 class: "{class}",
                 href: "{props.href}",
-                "data-turbo-frame": "main-content",
-                img { width: "16", height: "16", src: "{props.icon}" }
+                "aria-current": selected.then_some("page"),
+                ..navigation,
+                {nav_icon(&Some(props.icon))}
                 "{props.title}"
+                {nav_badge(props.badge)}
             }
         }
     )
@@ -35,18 +159,50 @@ pub struct NavSubItemProps {
     title: String,
     selected_item_id: Option<String>,
     id: Option<String>,
+    icon: Option<NavIcon>,
+    #[props(into, default)]
+    navigation: Navigation,
+    /// A count shown after the title
+    badge: Option<usize>,
+}
+
+/// What a `NavSubGroup` shares with the items inside it.
+#[derive(Clone)]
+struct SubGroupSelection {
+    selected_item_id: Option<String>,
+    /// Set by a selected item to open this group and the groups around it
+    contains_selected: Vec<Signal<bool>>,
 }
 
 #[component]
 pub fn NavSubItem(props: NavSubItemProps) -> Element {
-    let class = match (&props.id, &props.selected_item_id) {
-        (Some(id), Some(selected_id)) if id == selected_id => "active",
-        _ => "",
-    };
+    let selection = try_consume_context::<SubGroupSelection>();
+    let selected_item_id = props.selected_item_id.clone().or_else(|| {
+        selection
+            .as_ref()
+            .and_then(|selection| selection.selected_item_id.clone())
+    });
+    let selected = is_selected(&props.id, &selected_item_id);
+    if selected {
+        for mut open in selection.into_iter().flat_map(|s| s.contains_selected) {
+            if !*open.peek() {
+                open.set(true);
+            }
+        }
+    }
+    let class = if selected { "active" } else { "" };
+    let navigation = props.navigation.attributes(&props.href);
 
     rsx!(
         li { class,
-            a { href: "{props.href}", "{props.title}" }
+            a {
+                href: "{props.href}",
+                "aria-current": selected.then_some("page"),
+                ..navigation,
+                {nav_icon(&props.icon)}
+                "{props.title}"
+                {nav_badge(props.badge)}
+            }
         }
     )
 }
@@ -69,12 +225,87 @@ pub fn NavGroup(props: NavGroupProps) -> Element {
 
 #[derive(Props, Clone, PartialEq)]
 pub struct NavSubGroupProps {
+    /// Shown above the items. Collapsible groups show it as a `summary`.
+    title: Option<String>,
+    icon: Option<NavIcon>,
+    badge: Option<usize>,
+    /// Collapse the items under the title, needs a `title`
+    #[props(default = true)]
+    collapsible: bool,
+    /// Passed on to the `NavSubItem`s inside that don't set their own
+    selected_item_id: Option<String>,
+    /// Start open even when no item inside is selected
+    #[props(default)]
+    open: bool,
     children: Element,
 }
 
+/// A nested DaisyUI menu, collapsible behind its title. It opens when a
+/// `NavSubItem` inside it, at any depth, is selected.
+///
+/// The items report their selection as they render, after the group, so
+/// render it with the `ssr` module, which re-renders the group before
+/// writing it out.
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
+/// NavSubGroup {
+///     title: "Settings",
+///     selected_item_id: "profile",
+///     NavSubItem { id: "profile", href: "/settings/profile", title: "Profile" }
+///     NavSubItem { id: "billing", href: "/settings/billing", title: "Billing" }
+/// }
+/// # }}
+/// ```
 #[component]
 pub fn NavSubGroup(props: NavSubGroupProps) -> Element {
+    let contains_selected = use_hook(|| Signal::new(false));
+    use_hook(|| {
+        let outer = try_consume_context::<SubGroupSelection>();
+        let mut groups = outer
+            .as_ref()
+            .map(|outer| outer.contains_selected.clone())
+            .unwrap_or_default();
+        groups.push(contains_selected);
+        provide_context(SubGroupSelection {
+            selected_item_id: props
+                .selected_item_id
+                .clone()
+                .or_else(|| outer.and_then(|outer| outer.selected_item_id)),
+            contains_selected: groups,
+        })
+    });
+    let open = props.open || contains_selected();
+    let items = rsx!(
+        ul { role: "list", {props.children} }
+    );
+
     rsx!(
-        ul { role: "list", class: "ActionList ActionList--subGroup", {props.children} }
+        li {
+            match (&props.title, props.collapsible) {
+                (Some(title), true) => rsx! {
+                    details { open,
+                        summary {
+                            {nav_icon(&props.icon)}
+                            "{title}"
+                            {nav_badge(props.badge)}
+                        }
+                        {items}
+                    }
+                },
+                (Some(title), false) => rsx! {
+                    h2 { class: "menu-title",
+                        {nav_icon(&props.icon)}
+                        "{title}"
+                        {nav_badge(props.badge)}
+                    }
+                    {items}
+                },
+                (None, _) => items,
+            }
+        }
     )
 }
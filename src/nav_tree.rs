@@ -0,0 +1,294 @@
+#![allow(non_snake_case)]
+//! Sidebar navigation defined once as data.
+//!
+//! ```rust
+//! # use daisy_rsx::*;
+//! # use dioxus::prelude::*;
+//! fn navigation() -> NavTree {
+//!     NavTree::new()
+//!         .group(
+//!             NavTreeGroup::new("Workspace")
+//!                 .item(NavTreeItem::new("Dashboard", "/").icon("/icons/home.svg"))
+//!                 .item(
+//!                     NavTreeItem::new("Projects", "/projects")
+//!                         .child(NavTreeItem::new("Active", "/projects/active"))
+//!                         .child(NavTreeItem::new("Archived", "/projects/archived")),
+//!                 ),
+//!         )
+//!         .group(
+//!             NavTreeGroup::new("Admin")
+//!                 .item(NavTreeItem::new("Users", "/admin/users").permission("admin")),
+//!         )
+//! }
+//!
+//! # fn app(is_admin: bool) -> Element { rsx! {
+//! SidebarNav {
+//!     tree: navigation(),
+//!     current_path: "/projects/active",
+//!     permissions: NavPermissions::new(move |permission| is_admin && permission == "admin"),
+//! }
+//! # }}
+//! ```
+use std::fmt;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::nav_item::{NavGroup, NavIcon, NavSubGroup, NavSubItem, Navigation};
+
+/// The groups of links in a sidebar.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NavTree {
+    pub groups: Vec<NavTreeGroup>,
+}
+
+impl NavTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn group(mut self, group: NavTreeGroup) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    /// The tree without the items `allowed` rejects, or groups and parents
+    /// left empty by them.
+    pub fn visible(&self, allowed: impl Fn(&str) -> bool) -> NavTree {
+        NavTree {
+            groups: self
+                .groups
+                .iter()
+                .map(|group| NavTreeGroup {
+                    heading: group.heading.clone(),
+                    items: visible_items(&group.items, &allowed),
+                })
+                .filter(|group| !group.items.is_empty())
+                .collect(),
+        }
+    }
+
+    /// The `href` of the item for `current_path`: an exact match, or else
+    /// the longest `href` that `current_path` is below. Parents only match
+    /// exactly, so `/projects/42` selects `Projects` unless it has children.
+    /// A root `/` item also only matches exactly, so pages missing from the
+    /// tree select nothing rather than the root.
+    pub fn selected_href(&self, current_path: &str) -> Option<&str> {
+        let path = current_path.split(['?', '#']).next().unwrap_or_default();
+        let mut best: Option<&str> = None;
+        for group in &self.groups {
+            for item in &group.items {
+                select(item, path, &mut best);
+            }
+        }
+        best
+    }
+}
+
+fn visible_items(items: &[NavTreeItem], allowed: &impl Fn(&str) -> bool) -> Vec<NavTreeItem> {
+    items
+        .iter()
+        .filter(|item| item.permission.as_deref().is_none_or(allowed))
+        .filter_map(|item| {
+            let children = visible_items(&item.children, allowed);
+            if !item.children.is_empty() && children.is_empty() {
+                return None;
+            }
+            Some(NavTreeItem {
+                children,
+                ..item.clone()
+            })
+        })
+        .collect()
+}
+
+fn select<'a>(item: &'a NavTreeItem, path: &str, best: &mut Option<&'a str>) {
+    let href = item.href.as_str();
+    let prefix = href.trim_end_matches('/');
+    let below =
+        item.children.is_empty() && !prefix.is_empty() && path.starts_with(&format!("{prefix}/"));
+    if href == path || below {
+        let better = match *best {
+            Some(current) => current != path && (href == path || href.len() > current.len()),
+            None => true,
+        };
+        if better {
+            *best = Some(href);
+        }
+    }
+    for child in &item.children {
+        select(child, path, best);
+    }
+}
+
+/// A headed group of items.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NavTreeGroup {
+    pub heading: String,
+    pub items: Vec<NavTreeItem>,
+}
+
+impl NavTreeGroup {
+    pub fn new(heading: impl Into<String>) -> Self {
+        Self {
+            heading: heading.into(),
+            items: Vec::new(),
+        }
+    }
+
+    pub fn item(mut self, item: NavTreeItem) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+/// A link, or with children a collapsible parent. A parent's own link is
+/// the first item inside it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NavTreeItem {
+    pub title: String,
+    pub href: String,
+    pub icon: Option<NavIcon>,
+    pub badge: Option<usize>,
+    /// Only shown when the `SidebarNav`'s permissions allow this
+    pub permission: Option<String>,
+    /// Overrides the `SidebarNav`'s navigation for this link
+    pub navigation: Option<Navigation>,
+    pub children: Vec<NavTreeItem>,
+}
+
+impl NavTreeItem {
+    pub fn new(title: impl Into<String>, href: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            href: href.into(),
+            icon: None,
+            badge: None,
+            permission: None,
+            navigation: None,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<NavIcon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn badge(mut self, count: usize) -> Self {
+        self.badge = Some(count);
+        self
+    }
+
+    pub fn permission(mut self, permission: impl Into<String>) -> Self {
+        self.permission = Some(permission.into());
+        self
+    }
+
+    pub fn navigation(mut self, navigation: impl Into<Navigation>) -> Self {
+        self.navigation = Some(navigation.into());
+        self
+    }
+
+    pub fn child(mut self, child: NavTreeItem) -> Self {
+        self.children.push(child);
+        self
+    }
+}
+
+/// Decides which permissions the current user has.
+#[derive(Clone)]
+pub struct NavPermissions(Rc<dyn Fn(&str) -> bool>);
+
+impl NavPermissions {
+    pub fn new(allowed: impl Fn(&str) -> bool + 'static) -> Self {
+        Self(Rc::new(allowed))
+    }
+
+    pub fn allows(&self, permission: &str) -> bool {
+        (self.0)(permission)
+    }
+}
+
+impl PartialEq for NavPermissions {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for NavPermissions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NavPermissions")
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SidebarNavProps {
+    pub tree: NavTree,
+    /// The path of the current page, used to select its item
+    #[props(into)]
+    pub current_path: String,
+    /// Hides items whose permission this rejects. Without it they're all shown.
+    pub permissions: Option<NavPermissions>,
+    /// How the links load their pages
+    #[props(into, default)]
+    pub navigation: Navigation,
+}
+
+/// Renders a [`NavTree`] as DaisyUI menus, selecting the item for the
+/// current page and opening its parents.
+#[component]
+pub fn SidebarNav(props: SidebarNavProps) -> Element {
+    let tree = match &props.permissions {
+        Some(permissions) => props
+            .tree
+            .visible(|permission| permissions.allows(permission)),
+        None => props.tree.clone(),
+    };
+    let selected = tree.selected_href(&props.current_path).map(str::to_string);
+
+    rsx!(for group in tree.groups.iter() {
+        NavGroup {
+            heading: "{group.heading}",
+            content: rsx! {
+                for item in group.items.iter() {
+                    {tree_item(item, &selected, &props.navigation)}
+                }
+            },
+        }
+    })
+}
+
+fn tree_item(item: &NavTreeItem, selected: &Option<String>, navigation: &Navigation) -> Element {
+    // A parent's icon and badge are shown on its group's title instead
+    let leaf = item.children.is_empty();
+    let link = rsx!(NavSubItem {
+        id: item.href.clone(),
+        selected_item_id: selected.clone(),
+        href: item.href.clone(),
+        title: item.title.clone(),
+        icon: item.icon.clone().filter(|_| leaf),
+        badge: item.badge.filter(|_| leaf),
+        navigation: item
+            .navigation
+            .clone()
+            .unwrap_or_else(|| navigation.clone()),
+    });
+    if leaf {
+        return link;
+    }
+
+    // The group opens itself when the parent's link or a child is selected
+    rsx!(
+        NavSubGroup {
+            title: item.title.clone(),
+            icon: item.icon.clone(),
+            badge: item.badge,
+            selected_item_id: selected.clone(),
+            {link}
+            for child in item.children.iter() {
+                {tree_item(child, selected, navigation)}
+            }
+        }
+    )
+}
@@ -0,0 +1,310 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::csp::use_csp_nonce;
+
+/// Everything in the `<head>` of a page beyond its title and stylesheets,
+/// so public pages can be indexed and shared. Pass it to `AppLayout` as `meta`.
+///
+/// ```rust
+/// use daisy_rsx::{OpenGraph, PageMeta, Script};
+///
+/// let meta = PageMeta::new()
+///     .lang("en")
+///     .description("Pricing for teams of every size")
+///     .canonical("https://example.com/pricing")
+///     .open_graph(OpenGraph::new("Pricing").image("https://example.com/og.png"))
+///     .twitter_card("summary_large_image")
+///     .preconnect("https://fonts.gstatic.com")
+///     .script(Script::new("/analytics.js").defer());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PageMeta {
+    /// Written as `lang` on the `AppLayout` `<body>`, and on `<html>` by `ssr::render_page`
+    pub lang: Option<String>,
+    pub description: Option<String>,
+    pub canonical: Option<String>,
+    /// Such as `noindex, nofollow`
+    pub robots: Option<String>,
+    pub theme_color: Option<String>,
+    pub open_graph: Option<OpenGraph>,
+    /// The `twitter:card` type. Twitter reads the rest from the Open Graph tags.
+    pub twitter_card: Option<String>,
+    pub twitter_site: Option<String>,
+    pub preconnect: Vec<String>,
+    pub preload: Vec<Preload>,
+    pub scripts: Vec<Script>,
+    /// Anything else to add to the head
+    pub extra: Option<Element>,
+}
+
+impl PageMeta {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn canonical(mut self, url: impl Into<String>) -> Self {
+        self.canonical = Some(url.into());
+        self
+    }
+
+    pub fn robots(mut self, robots: impl Into<String>) -> Self {
+        self.robots = Some(robots.into());
+        self
+    }
+
+    pub fn theme_color(mut self, color: impl Into<String>) -> Self {
+        self.theme_color = Some(color.into());
+        self
+    }
+
+    pub fn open_graph(mut self, open_graph: OpenGraph) -> Self {
+        self.open_graph = Some(open_graph);
+        self
+    }
+
+    pub fn twitter_card(mut self, card: impl Into<String>) -> Self {
+        self.twitter_card = Some(card.into());
+        self
+    }
+
+    pub fn twitter_site(mut self, handle: impl Into<String>) -> Self {
+        self.twitter_site = Some(handle.into());
+        self
+    }
+
+    pub fn preconnect(mut self, origin: impl Into<String>) -> Self {
+        self.preconnect.push(origin.into());
+        self
+    }
+
+    pub fn preload(mut self, preload: Preload) -> Self {
+        self.preload.push(preload);
+        self
+    }
+
+    pub fn script(mut self, script: Script) -> Self {
+        self.scripts.push(script);
+        self
+    }
+
+    pub fn extra(mut self, extra: Element) -> Self {
+        self.extra = Some(extra);
+        self
+    }
+}
+
+/// Open Graph tags used for link previews.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OpenGraph {
+    pub title: String,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub url: Option<String>,
+    pub site_name: Option<String>,
+    /// `og:type`, `website` by default
+    pub kind: String,
+}
+
+impl OpenGraph {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            image: None,
+            url: None,
+            site_name: None,
+            kind: "website".to_string(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn image(mut self, image: impl Into<String>) -> Self {
+        self.image = Some(image.into());
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn site_name(mut self, site_name: impl Into<String>) -> Self {
+        self.site_name = Some(site_name.into());
+        self
+    }
+
+    pub fn kind(mut self, kind: impl Into<String>) -> Self {
+        self.kind = kind.into();
+        self
+    }
+}
+
+/// A `<link rel="preload">`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Preload {
+    pub href: String,
+    /// The `as` attribute: `font`, `image`, `script`, `style`...
+    pub kind: String,
+    pub mime_type: Option<String>,
+    pub crossorigin: bool,
+}
+
+impl Preload {
+    pub fn new(href: impl Into<String>, kind: impl Into<String>) -> Self {
+        Self {
+            href: href.into(),
+            kind: kind.into(),
+            mime_type: None,
+            crossorigin: false,
+        }
+    }
+
+    /// A font, which browsers only preload with `crossorigin`.
+    pub fn font(href: impl Into<String>, mime_type: impl Into<String>) -> Self {
+        Self {
+            mime_type: Some(mime_type.into()),
+            crossorigin: true,
+            ..Self::new(href, "font")
+        }
+    }
+
+    pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    pub fn crossorigin(mut self) -> Self {
+        self.crossorigin = true;
+        self
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ScriptLoading {
+    #[default]
+    Blocking,
+    Defer,
+    Async,
+}
+
+/// An external script. Without its own `nonce` it uses the page's.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Script {
+    pub src: String,
+    pub loading: ScriptLoading,
+    pub module: bool,
+    pub nonce: Option<String>,
+}
+
+impl Script {
+    pub fn new(src: impl Into<String>) -> Self {
+        Self {
+            src: src.into(),
+            loading: ScriptLoading::Blocking,
+            module: false,
+            nonce: None,
+        }
+    }
+
+    pub fn defer(mut self) -> Self {
+        self.loading = ScriptLoading::Defer;
+        self
+    }
+
+    pub fn r#async(mut self) -> Self {
+        self.loading = ScriptLoading::Async;
+        self
+    }
+
+    /// Load as `type="module"`, which is deferred by default.
+    pub fn module(mut self) -> Self {
+        self.module = true;
+        self
+    }
+
+    pub fn nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = Some(nonce.into());
+        self
+    }
+}
+
+/// Renders a `PageMeta` as tags for the `<head>`. `AppLayout` includes it,
+/// other layouts can use it directly.
+#[component]
+pub fn PageMetaTags(meta: PageMeta) -> Element {
+    let page_nonce = use_csp_nonce().map(|nonce| nonce.0);
+
+    rsx!(
+        if let Some(description) = &meta.description {
+            meta { name: "description", content: "{description}" }
+        }
+        if let Some(robots) = &meta.robots {
+            meta { name: "robots", content: "{robots}" }
+        }
+        if let Some(theme_color) = &meta.theme_color {
+            meta { name: "theme-color", content: "{theme_color}" }
+        }
+        if let Some(canonical) = &meta.canonical {
+            link { rel: "canonical", href: "{canonical}" }
+        }
+        if let Some(og) = &meta.open_graph {
+            meta { property: "og:type", content: "{og.kind}" }
+            meta { property: "og:title", content: "{og.title}" }
+            if let Some(description) = &og.description {
+                meta { property: "og:description", content: "{description}" }
+            }
+            if let Some(image) = &og.image {
+                meta { property: "og:image", content: "{image}" }
+            }
+            if let Some(url) = &og.url {
+                meta { property: "og:url", content: "{url}" }
+            }
+            if let Some(site_name) = &og.site_name {
+                meta { property: "og:site_name", content: "{site_name}" }
+            }
+        }
+        if let Some(card) = &meta.twitter_card {
+            meta { name: "twitter:card", content: "{card}" }
+        }
+        if let Some(site) = &meta.twitter_site {
+            meta { name: "twitter:site", content: "{site}" }
+        }
+        for origin in &meta.preconnect {
+            link { rel: "preconnect", href: "{origin}", crossorigin: "" }
+        }
+        for preload in &meta.preload {
+            link {
+                rel: "preload",
+                href: "{preload.href}",
+                "as": "{preload.kind}",
+                "type": preload.mime_type.clone(),
+                crossorigin: preload.crossorigin.then_some(""),
+            }
+        }
+        for script in &meta.scripts {
+            script {
+                src: "{script.src}",
+                "type": script.module.then_some("module"),
+                defer: (script.loading == ScriptLoading::Defer).then_some(true),
+                "async": (script.loading == ScriptLoading::Async).then_some(true),
+                nonce: script.nonce.clone().or(page_nonce.clone()),
+            }
+        }
+        {meta.extra}
+    )
+}
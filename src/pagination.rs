@@ -1,33 +1,335 @@
 #![allow(non_snake_case)]
+use std::rc::Rc;
+
 use dioxus::prelude::*;
 
+use crate::htmx::Hx;
+use crate::query::{with_params, without_params};
+use crate::size::{Size, SizePrefix};
+
 #[derive(Props, Clone, PartialEq)]
 pub struct PaginationProps {
     next_page_url: Option<String>,
     prev_page_url: Option<String>,
+    #[props(default)]
+    pagination_size: Size,
+    /// Load pages with htmx
+    hx: Option<Hx>,
+}
+
+impl SizePrefix for PaginationProps {
+    const SIZE_PREFIX: &'static str = "btn";
 }
 
 #[component]
 pub fn Pagination(props: PaginationProps) -> Element {
+    let size = props.pagination_size.class::<PaginationProps>();
+
     rsx!(
-        nav { class: "paginate-container", "aria-label": "Pagination",
-            div { class: "pagination",
-                if let Some(url) = props.prev_page_url {
-                    a {
-                        class: "previous_page",
-                        rel: "previous",
-                        href: "{url}",
-                        "Previous"
+        nav { "aria-label": "Pagination",
+            div { class: "join",
+                PageLink {
+                    href: props.prev_page_url,
+                    hx: props.hx.clone(),
+                    rel: "prev",
+                    size: size.clone(),
+                    "Previous"
+                }
+                PageLink {
+                    href: props.next_page_url,
+                    hx: props.hx,
+                    rel: "next",
+                    size,
+                    "Next"
+                }
+            }
+        }
+    )
+}
+
+/// How [`NumberedPagination`] builds the link for a page number.
+#[derive(Clone)]
+pub enum PageUrl {
+    /// Set `param` on `url`, keeping its other query parameters.
+    Query {
+        url: String,
+        param: String,
+    },
+    /// Replace `{page}` in the template, e.g. `/posts/page/{page}`.
+    Template(String),
+    Custom(Rc<dyn Fn(usize) -> String>),
+}
+
+impl PageUrl {
+    /// Set the `page` parameter on `url`.
+    pub fn query(url: impl Into<String>) -> Self {
+        PageUrl::Query {
+            url: url.into(),
+            param: "page".to_string(),
+        }
+    }
+
+    pub fn custom(f: impl Fn(usize) -> String + 'static) -> Self {
+        PageUrl::Custom(Rc::new(f))
+    }
+
+    pub fn href(&self, page: usize) -> String {
+        match self {
+            PageUrl::Query { url, param } => with_params(url, &[(param, &page.to_string())]),
+            PageUrl::Template(template) => template.replace("{page}", &page.to_string()),
+            PageUrl::Custom(f) => f(page),
+        }
+    }
+}
+
+impl PartialEq for PageUrl {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                PageUrl::Query { url, param },
+                PageUrl::Query {
+                    url: other_url,
+                    param: other_param,
+                },
+            ) => url == other_url && param == other_param,
+            (PageUrl::Template(a), PageUrl::Template(b)) => a == b,
+            (PageUrl::Custom(a), PageUrl::Custom(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PageItem {
+    Page(usize),
+    Ellipsis,
+}
+
+/// The pages to show: the first and last page, `window` pages either side of
+/// `current`, and an ellipsis for each gap. A gap of a single page shows that
+/// page instead since the ellipsis would take the same space.
+pub fn page_window(current: usize, total_pages: usize, window: usize) -> Vec<PageItem> {
+    let total_pages = total_pages.max(1);
+    let current = current.clamp(1, total_pages);
+    let start = current.saturating_sub(window).max(1);
+    let end = (current + window).min(total_pages);
+
+    let mut items = Vec::new();
+    if start > 1 {
+        items.push(PageItem::Page(1));
+        match start {
+            2 => {}
+            3 => items.push(PageItem::Page(2)),
+            _ => items.push(PageItem::Ellipsis),
+        }
+    }
+    items.extend((start..=end).map(PageItem::Page));
+    if end < total_pages {
+        match total_pages - end {
+            1 => {}
+            2 => items.push(PageItem::Page(total_pages - 1)),
+            _ => items.push(PageItem::Ellipsis),
+        }
+        items.push(PageItem::Page(total_pages));
+    }
+    items
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NumberedPaginationProps {
+    /// The current page, starting at 1
+    pub current_page: usize,
+    /// Total number of pages. Takes precedence over `total_items`.
+    pub total_pages: Option<usize>,
+    /// Total number of items, used with `page_size` when `total_pages` isn't known
+    pub total_items: Option<usize>,
+    #[props(default = 20)]
+    pub page_size: usize,
+    pub page_url: PageUrl,
+    /// Pages shown either side of the current page
+    #[props(default = 1)]
+    pub window: usize,
+    /// Show First and Last links
+    #[props(default = true)]
+    pub first_last: bool,
+    #[props(default)]
+    pub pagination_size: Size,
+    /// Load pages with htmx
+    pub hx: Option<Hx>,
+}
+
+impl SizePrefix for NumberedPaginationProps {
+    const SIZE_PREFIX: &'static str = "btn";
+}
+
+impl NumberedPaginationProps {
+    fn page_count(&self) -> usize {
+        let pages = match (self.total_pages, self.total_items) {
+            (Some(pages), _) => pages,
+            (None, Some(items)) => items.div_ceil(self.page_size.max(1)),
+            (None, None) => 1,
+        };
+        pages.max(1)
+    }
+}
+
+#[component]
+pub fn NumberedPagination(props: NumberedPaginationProps) -> Element {
+    let total = props.page_count();
+    let current = props.current_page.clamp(1, total);
+    let size = props.pagination_size.class::<NumberedPaginationProps>();
+    let href = |page: usize, enabled: bool| enabled.then(|| props.page_url.href(page));
+    let page_hx = |page: usize| {
+        props
+            .hx
+            .as_ref()
+            .map(|hx| hx.link_attributes(&props.page_url.href(page)))
+            .unwrap_or_default()
+    };
+
+    rsx!(
+        nav { "aria-label": "Pagination",
+            div { class: "join",
+                if props.first_last {
+                    PageLink {
+                        href: href(1, current > 1),
+                        hx: props.hx.clone(),
+                        rel: "first",
+                        size: size.clone(),
+                        "First"
                     }
-                } else {
-                    span { class: "previous_page", "aria-disabled": "true", "Previous" }
                 }
-                if let Some(url) = props.next_page_url {
-                    a { class: "next_page", rel: "next", href: "{url}", "Next" }
-                } else {
-                    span { class: "next_page", "aria-disabled": "true", "Next" }
+                PageLink {
+                    href: href(current - 1, current > 1),
+                    hx: props.hx.clone(),
+                    rel: "prev",
+                    size: size.clone(),
+                    "Previous"
+                }
+                for item in page_window(current, total, props.window) {
+                    match item {
+                        PageItem::Page(page) if page == current => rsx!(
+                            span {
+                                class: "join-item btn btn-active {size}",
+                                "aria-current": "page",
+                                "{page}"
+                            }
+                        ),
+                        PageItem::Page(page) => rsx!(
+                            a {
+                                class: "join-item btn {size}",
+                                href: props.page_url.href(page),
+                                "aria-label": "Page {page}",
+                                ..page_hx(page),
+                                "{page}"
+                            }
+                        ),
+                        PageItem::Ellipsis => rsx!(
+                            span {
+                                class: "join-item btn btn-disabled {size}",
+                                "aria-hidden": "true",
+                                "…"
+                            }
+                        ),
+                    }
+                }
+                PageLink {
+                    href: href(current + 1, current < total),
+                    hx: props.hx.clone(),
+                    rel: "next",
+                    size: size.clone(),
+                    "Next"
+                }
+                if props.first_last {
+                    PageLink {
+                        href: href(total, current < total),
+                        hx: props.hx.clone(),
+                        rel: "last",
+                        size: size.clone(),
+                        "Last"
+                    }
                 }
             }
         }
     )
 }
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CursorPaginationProps {
+    /// The current page URL; links keep its other query parameters
+    pub url: String,
+    /// Cursor of the first row shown, if there are rows before it
+    pub before: Option<String>,
+    /// Cursor of the last row shown, if there are rows after it
+    pub after: Option<String>,
+    #[props(into, default = "before".to_string())]
+    pub before_param: String,
+    #[props(into, default = "after".to_string())]
+    pub after_param: String,
+    #[props(default)]
+    pub pagination_size: Size,
+    /// Load pages with htmx
+    pub hx: Option<Hx>,
+}
+
+impl SizePrefix for CursorPaginationProps {
+    const SIZE_PREFIX: &'static str = "btn";
+}
+
+/// Previous / Next links for keyset pagination, where each page is addressed by
+/// the cursor of a neighbouring row rather than a page number.
+#[component]
+pub fn CursorPagination(props: CursorPaginationProps) -> Element {
+    let size = props.pagination_size.class::<CursorPaginationProps>();
+    let cursor_url = |param: &str, other: &str, cursor: &str| {
+        with_params(&without_params(&props.url, &[other]), &[(param, cursor)])
+    };
+    let prev = props
+        .before
+        .as_deref()
+        .map(|cursor| cursor_url(&props.before_param, &props.after_param, cursor));
+    let next = props
+        .after
+        .as_deref()
+        .map(|cursor| cursor_url(&props.after_param, &props.before_param, cursor));
+
+    rsx!(
+        nav { "aria-label": "Pagination",
+            div { class: "join",
+                PageLink {
+                    href: prev,
+                    hx: props.hx.clone(),
+                    rel: "prev",
+                    size: size.clone(),
+                    "Previous"
+                }
+                PageLink { href: next, hx: props.hx, rel: "next", size, "Next" }
+            }
+        }
+    )
+}
+
+/// A `join` button that links to `href`, or is disabled when there's nowhere to go.
+#[component]
+fn PageLink(
+    href: Option<String>,
+    hx: Option<Hx>,
+    rel: String,
+    size: String,
+    children: Element,
+) -> Element {
+    if let Some(href) = href {
+        let hx = hx.map(|hx| hx.link_attributes(&href)).unwrap_or_default();
+        rsx!(
+            a { class: "join-item btn {size}", rel, href, ..hx, {children} }
+        )
+    } else {
+        rsx!(
+            span {
+                class: "join-item btn btn-disabled {size}",
+                "aria-disabled": "true",
+                {children}
+            }
+        )
+    }
+}
@@ -0,0 +1,198 @@
+#![allow(non_snake_case)]
+//! Progress bars, including ones the server updates while a job runs.
+//!
+//! Give a `Progress` or `RadialProgress` an [`Sse`] and it's wrapped in an
+//! element that connects to the stream with htmx's `sse` extension. Each time
+//! the server sends the event, the new HTML replaces the bar:
+//!
+//! ```rust
+//! # use daisy_rsx::*;
+//! # use dioxus::prelude::*;
+//! // The page, which loads htmx and its sse extension
+//! # fn app() -> Element { rsx! {
+//! Progress {
+//!     value: 0,
+//!     sse: Sse::new("/exports/42/progress", "progress").close("done"),
+//! }
+//! # }}
+//!
+//! // The stream at /exports/42/progress sends the bar as the job advances,
+//! // with content type `text/event-stream`
+//! let message = ssr::render_sse_event("progress", rsx! { Progress { value: 40 } });
+//! assert_eq!(
+//!     message,
+//!     "event: progress\ndata: <progress class=\"progress \" max=\"100\" value=\"40\"></progress>\n\n"
+//! );
+//! ```
+//!
+//! Send a `done` event at the end to close the connection.
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::color::{Color, ColorPrefix};
+use crate::csp::InlineStyle;
+use crate::htmx::Sse;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ProgressProps {
+    /// How far along, out of `max`. Without it the bar is indeterminate.
+    pub value: Option<u32>,
+    #[props(default = 100)]
+    pub max: u32,
+    #[props(default)]
+    pub progress_color: Color,
+    /// Stream updated bars from the server
+    pub sse: Option<Sse>,
+    /// All standard HTML progress attributes (id, class, aria-label, etc.)
+    #[props(extends = progress, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl ColorPrefix for ProgressProps {
+    const COLOR_PREFIX: &'static str = "progress";
+}
+
+/// A progress bar with DaisyUI styling.
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
+/// Progress { value: 40, progress_color: Color::Primary, class: "w-56" }
+/// Progress { "aria-label": "Loading" }
+/// # }}
+/// ```
+#[component]
+pub fn Progress(props: ProgressProps) -> Element {
+    let color = props.progress_color.class::<ProgressProps>();
+    let value = props.value.map(|value| value.to_string());
+    let bar = rsx!(
+        progress {
+            class: "progress {color}",
+            max: "{props.max}",
+            value,
+            ..props.attributes,
+        }
+    );
+
+    live(props.sse, bar)
+}
+
+/// A CSS length, for sizing a `RadialProgress`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CssLength {
+    Px(f32),
+    Rem(f32),
+    Em(f32),
+    Percent(f32),
+}
+
+impl CssLength {
+    /// The length as part of a class name, e.g. `1_5rem` for `1.5rem`.
+    fn class_part(&self) -> String {
+        self.to_string()
+            .replace('.', "_")
+            .replace('-', "m")
+            .replace('%', "pc")
+    }
+}
+
+impl Display for CssLength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CssLength::Px(value) => write!(f, "{value}px"),
+            CssLength::Rem(value) => write!(f, "{value}rem"),
+            CssLength::Em(value) => write!(f, "{value}em"),
+            CssLength::Percent(value) => write!(f, "{value}%"),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RadialProgressProps {
+    /// Percentage, from 0 to 100
+    pub value: u32,
+    /// Diameter, `5rem` by default
+    pub size: Option<CssLength>,
+    /// Width of the ring, a tenth of the size by default
+    pub thickness: Option<CssLength>,
+    /// Shown in the middle, the percentage by default
+    pub label: Option<String>,
+    #[props(default)]
+    pub radial_color: Color,
+    /// Stream updated rings from the server
+    pub sse: Option<Sse>,
+    /// All standard HTML div attributes (id, class, aria-label, etc.)
+    #[props(extends = div, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl ColorPrefix for RadialProgressProps {
+    const COLOR_PREFIX: &'static str = "text";
+}
+
+/// A circular progress indicator with DaisyUI styling.
+///
+/// The ring's CSS variables are set by a `style` element carrying the page's
+/// CSP nonce, so it works under the strict policy from the `csp` module. The
+/// rule targets a class named after the value, size and thickness, so rings
+/// that look the same share it.
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
+/// RadialProgress {
+///     value: 70,
+///     size: CssLength::Rem(8.0),
+///     thickness: CssLength::Px(4.0),
+///     radial_color: Color::Success,
+/// }
+/// # }}
+/// ```
+#[component]
+pub fn RadialProgress(props: RadialProgressProps) -> Element {
+    let color = props.radial_color.class::<RadialProgressProps>();
+    let value = props.value.min(100);
+    let mut scope = format!("radial-progress-{value}");
+    let mut variables = format!("--value:{value};");
+    if let Some(size) = &props.size {
+        scope.push_str(&format!("-s{}", size.class_part()));
+        variables.push_str(&format!("--size:{size};"));
+    }
+    if let Some(thickness) = &props.thickness {
+        scope.push_str(&format!("-t{}", thickness.class_part()));
+        variables.push_str(&format!("--thickness:{thickness};"));
+    }
+    let label = props.label.unwrap_or_else(|| format!("{value}%"));
+    let ring = rsx!(
+        InlineStyle { css: ".{scope}{{{variables}}}" }
+        div {
+            class: "radial-progress {scope} {color}",
+            role: "progressbar",
+            "aria-valuenow": "{value}",
+            "aria-valuemin": "0",
+            "aria-valuemax": "100",
+            ..props.attributes,
+            "{label}"
+        }
+    );
+
+    live(props.sse, ring)
+}
+
+/// Wraps `element` in the element receiving `sse` updates, if any.
+fn live(sse: Option<Sse>, element: Element) -> Element {
+    match sse {
+        Some(sse) => {
+            let attributes = sse.attributes();
+            rsx!(
+                div { ..attributes, {element} }
+            )
+        }
+        None => element,
+    }
+}
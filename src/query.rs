@@ -0,0 +1,97 @@
+//! Small query string helpers for components that render links back to the
+//! current page (sorting, pagination).
+
+/// Returns `url` with each of `params` set, replacing any existing values for
+/// the same keys and keeping every other parameter and the fragment.
+pub fn with_params(url: &str, params: &[(&str, &str)]) -> String {
+    let keys: Vec<&str> = params.iter().map(|(name, _)| *name).collect();
+    let added: Vec<String> = params
+        .iter()
+        .map(|(name, value)| format!("{}={}", encode(name), encode(value)))
+        .collect();
+    rebuild(url, &keys, &added)
+}
+
+/// Returns `url` with every parameter named in `keys` removed.
+pub fn without_params(url: &str, keys: &[&str]) -> String {
+    rebuild(url, keys, &[])
+}
+
+fn rebuild(url: &str, removed: &[&str], added: &[String]) -> String {
+    let (rest, fragment) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    };
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+    let mut pairs: Vec<&str> = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .filter(|pair| {
+            let key = pair.split_once('=').map_or(*pair, |(key, _)| key);
+            !removed.iter().any(|name| encode(name) == key)
+        })
+        .collect();
+    pairs.extend(added.iter().map(String::as_str));
+
+    let mut out = path.to_string();
+    if !pairs.is_empty() {
+        out.push('?');
+        out.push_str(&pairs.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        out.push('#');
+        out.push_str(fragment);
+    }
+    out
+}
+
+/// The decoded value of the first parameter called `name` in `url`, which may
+/// be a full URL or just its query string, with or without the leading `?`.
+pub fn param(url: &str, name: &str) -> Option<String> {
+    let url = url.split_once('#').map_or(url, |(rest, _)| rest);
+    let query = url.split_once('?').map_or(url, |(_, query)| query);
+    query
+        .split('&')
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+        .find(|(key, _)| decode(key) == name)
+        .map(|(_, value)| decode(value))
+}
+
+/// Percent-encodes everything except RFC 3986 unreserved characters.
+pub fn encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+/// Reverses [`encode`], also accepting `+` for a space.
+pub fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+            (b'+', _) => out.push(b' '),
+            (byte, _) => out.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
@@ -0,0 +1,193 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+
+use crate::color::{Color, ColorPrefix};
+use crate::form::{FieldMessages, FieldState, sets, text_attribute};
+use crate::size::{Size, SizePrefix};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioProps {
+    /// Label text displayed next to the radio button
+    pub label: String,
+    /// Name, validation errors and hint from the server. The radio is
+    /// checked when the field's value is its `value`.
+    pub field: Option<FieldState>,
+    #[props(default)]
+    pub radio_size: Size,
+    #[props(default)]
+    pub radio_scheme: Color,
+    /// All standard HTML input attributes (name, value, checked, onchange, etc.)
+    #[props(extends = input, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl ColorPrefix for RadioProps {
+    const COLOR_PREFIX: &'static str = "radio";
+}
+
+impl SizePrefix for RadioProps {
+    const SIZE_PREFIX: &'static str = "radio";
+}
+
+/// A radio button with DaisyUI styling.
+///
+/// Radios sharing a `field` would repeat its hint and errors, so render
+/// `FieldMessages` for it once after them, or use [`RadioGroup`].
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element {
+/// let field = FieldState::new("billing").value("yearly").hint("Change it any time");
+/// rsx! {
+///     Radio { label: "Monthly", field: field.clone(), value: "monthly" }
+///     Radio { label: "Yearly", field: field.clone(), value: "yearly" }
+///     FieldMessages { field }
+/// }
+/// # }
+/// ```
+#[component]
+pub fn Radio(props: RadioProps) -> Element {
+    let scheme = props.radio_scheme.class::<RadioProps>();
+    let size = props.radio_size.class::<RadioProps>();
+    let field_attributes = props
+        .field
+        .as_ref()
+        .map(|field| radio_attributes(field, &props.attributes))
+        .unwrap_or_default();
+    let error = props
+        .field
+        .as_ref()
+        .map(FieldState::error_class::<RadioProps>)
+        .unwrap_or_default();
+    let checked = props
+        .field
+        .as_ref()
+        .and_then(|field| field.value.as_deref())
+        .is_some_and(|current| {
+            text_attribute(&props.attributes, "value") == Some(current)
+                && !sets(&props.attributes, "checked")
+        })
+        .then_some(true);
+
+    rsx!(
+        label { class: "flex items-center gap-2 cursor-pointer",
+            input {
+                r#type: "radio",
+                class: "radio {scheme} {size} {error}",
+                checked,
+                ..field_attributes,
+                ..props.attributes,
+            }
+            span { "{props.label}" }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioGroupProps<T: Clone + PartialEq + Display + FromStr + 'static> {
+    /// Legend text displayed above the options
+    pub legend: String,
+    /// Name, value, validation errors and hint from the server. The option
+    /// whose value parses from the field's value is checked.
+    pub field: FieldState,
+    /// The options as `(value, label, description)`, skipping empty
+    /// descriptions
+    pub options: Vec<(T, String, String)>,
+    #[props(default)]
+    pub radio_size: Size,
+    #[props(default)]
+    pub radio_scheme: Color,
+}
+
+/// One radio button per option of an enum, or anything else that round-trips
+/// through `Display` and `FromStr`.
+///
+/// # Example
+/// ```rust
+/// # use std::fmt::Display;
+/// # use std::str::FromStr;
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Plan {
+///     Free,
+///     Team,
+/// }
+/// # impl Display for Plan {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         f.write_str(match self { Plan::Free => "free", Plan::Team => "team" })
+/// #     }
+/// # }
+/// # impl FromStr for Plan {
+/// #     type Err = ();
+/// #     fn from_str(s: &str) -> Result<Self, ()> {
+/// #         match s { "free" => Ok(Plan::Free), "team" => Ok(Plan::Team), _ => Err(()) }
+/// #     }
+/// # }
+///
+/// # fn app() -> Element { rsx! {
+/// RadioGroup {
+///     legend: "Plan",
+///     field: FieldState::new("plan").value(Plan::Team.to_string()),
+///     options: vec![
+///         (Plan::Free, "Free".to_string(), "For personal projects".to_string()),
+///         (Plan::Team, "Team".to_string(), "Shared workspaces".to_string()),
+///     ],
+/// }
+/// # }}
+/// ```
+#[component]
+pub fn RadioGroup<T: Clone + PartialEq + Display + FromStr + 'static>(
+    props: RadioGroupProps<T>,
+) -> Element {
+    let scheme = props.radio_scheme.class::<RadioProps>();
+    let size = props.radio_size.class::<RadioProps>();
+    let error = props.field.error_class::<RadioProps>();
+    let selected = props
+        .field
+        .value
+        .as_deref()
+        .and_then(|value| value.parse::<T>().ok());
+    let id = props.field.id();
+    let field_attributes = radio_attributes(&props.field, &[]);
+
+    rsx!(
+        fieldset { class: "fieldset",
+            legend { class: "fieldset-legend", "{props.legend}" }
+            for (index , (value , label , description)) in props.options.iter().enumerate() {
+                label { class: "flex items-start gap-2 cursor-pointer",
+                    input {
+                        r#type: "radio",
+                        class: "radio {scheme} {size} {error}",
+                        id: "{id}-{index}",
+                        value: "{value}",
+                        checked: (selected.as_ref() == Some(value)).then_some(true),
+                        ..field_attributes.clone(),
+                    }
+                    span { class: "flex flex-col",
+                        span { "{label}" }
+                        if !description.is_empty() {
+                            span { class: "text-sm opacity-70", "{description}" }
+                        }
+                    }
+                }
+            }
+            FieldMessages { field: props.field.clone() }
+        }
+    )
+}
+
+/// The field's attributes that `explicit` doesn't set, without its `id`,
+/// which can't be shared by the radios for one field.
+fn radio_attributes(field: &FieldState, explicit: &[Attribute]) -> Vec<Attribute> {
+    field
+        .attributes_except(explicit)
+        .into_iter()
+        .filter(|attribute| attribute.name != "id")
+        .collect()
+}
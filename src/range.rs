@@ -1,84 +1,69 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
-pub enum RangeColor {
-    #[default]
-    Default,
-    Primary,
-    Secondary,
-    Accent,
-    Info,
-    Success,
-    Warning,
-    Error,
-}
-
-impl Display for RangeColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            RangeColor::Default => write!(f, ""),
-            RangeColor::Primary => write!(f, "range-primary"),
-            RangeColor::Secondary => write!(f, "range-secondary"),
-            RangeColor::Accent => write!(f, "range-accent"),
-            RangeColor::Info => write!(f, "range-info"),
-            RangeColor::Success => write!(f, "range-success"),
-            RangeColor::Warning => write!(f, "range-warning"),
-            RangeColor::Error => write!(f, "range-error"),
-        }
-    }
-}
+use crate::color::{Color, ColorPrefix};
+use crate::form::{FieldMessages, FieldState};
+use crate::size::{Size, SizePrefix};
 
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum RangeSize {
-    #[default]
-    Default,
-    ExtraSmall,
-    Small,
-    Medium,
-    Large,
-}
+#[deprecated(since = "0.1.48", note = "use `Color` instead")]
+pub type RangeColor = Color;
 
-impl Display for RangeSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            RangeSize::Default => write!(f, ""),
-            RangeSize::ExtraSmall => write!(f, "range-xs"),
-            RangeSize::Small => write!(f, "range-sm"),
-            RangeSize::Medium => write!(f, "range-md"),
-            RangeSize::Large => write!(f, "range-lg"),
-        }
-    }
-}
+#[deprecated(since = "0.1.48", note = "use `Size` instead")]
+pub type RangeSize = Size;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct RangeProps {
     /// Label text displayed above the range
     pub label: String,
+    /// Name, value, validation errors and hint from the server
+    pub field: Option<FieldState>,
     #[props(default)]
-    pub range_color: RangeColor,
+    pub range_color: Color,
     #[props(default)]
-    pub range_size: RangeSize,
+    pub range_size: Size,
     /// All standard HTML input attributes (name, min, max, value, step, oninput, etc.)
     #[props(extends = input, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+impl ColorPrefix for RangeProps {
+    const COLOR_PREFIX: &'static str = "range";
+}
+
+impl SizePrefix for RangeProps {
+    const SIZE_PREFIX: &'static str = "range";
+}
+
 #[component]
 pub fn Range(props: RangeProps) -> Element {
-    let color = props.range_color.to_string();
-    let size = props.range_size.to_string();
+    let color = props.range_color.class::<RangeProps>();
+    let size = props.range_size.class::<RangeProps>();
+    let field_attributes = props
+        .field
+        .as_ref()
+        .map(|field| field.attributes_except(&props.attributes))
+        .unwrap_or_default();
+    let error = props
+        .field
+        .as_ref()
+        .map(FieldState::error_class::<RangeProps>)
+        .unwrap_or_default();
+    let value = props
+        .field
+        .as_ref()
+        .and_then(|field| field.value_except(&props.attributes));
 
     rsx!(
         label { class: "flex flex-col gap-1",
             span { "{props.label}" }
             input {
                 r#type: "range",
-                class: "range {color} {size}",
+                class: "range {color} {size} {error}",
+                value,
+                ..field_attributes,
                 ..props.attributes,
             }
+            FieldMessages { field: props.field }
         }
     )
 }
@@ -0,0 +1,153 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::color::{Color, ColorPrefix};
+use crate::size::{Size, SizePrefix};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RatingMask {
+    #[default]
+    Star,
+    Star2,
+    Heart,
+}
+
+impl Display for RatingMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RatingMask::Star => write!(f, "mask-star"),
+            RatingMask::Star2 => write!(f, "mask-star-2"),
+            RatingMask::Heart => write!(f, "mask-heart"),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RatingProps {
+    /// Name the chosen value is posted as
+    pub name: String,
+    /// The current rating, clamped to `0..=count`. Read-only ratings round it
+    /// to the nearest step.
+    pub value: Option<f64>,
+    /// Number of stars or hearts
+    #[props(default = 5)]
+    pub count: u32,
+    #[props(default)]
+    pub mask: RatingMask,
+    /// Allow half steps, posting values like `3.5`
+    #[props(default)]
+    pub half: bool,
+    /// Show the rating without letting it change
+    #[props(default)]
+    pub read_only: bool,
+    #[props(default)]
+    pub rating_size: Size,
+    #[props(default)]
+    pub rating_color: Color,
+    /// All standard HTML div attributes (id, class, etc.)
+    #[props(extends = div, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl ColorPrefix for RatingProps {
+    const COLOR_PREFIX: &'static str = "bg";
+}
+
+impl SizePrefix for RatingProps {
+    const SIZE_PREFIX: &'static str = "rating";
+}
+
+/// Stars or hearts built from radio buttons, so the chosen value posts with
+/// the form without any JavaScript.
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
+/// form { method: "post", action: "/feedback",
+///     Rating { name: "score", value: 4.0, rating_color: Color::Warning }
+///     Rating { name: "love", mask: RatingMask::Heart, half: true, count: 3 }
+///     Button { r#type: "submit", "Send" }
+/// }
+/// Rating { name: "average", value: 3.7, half: true, read_only: true }
+/// # }}
+/// ```
+#[component]
+pub fn Rating(props: RatingProps) -> Element {
+    let size = props.rating_size.class::<RatingProps>();
+    let color = props.rating_color.class::<RatingProps>();
+    let half = if props.half { "rating-half" } else { "" };
+    let mask = props.mask;
+    // Steps are counted in half stars so whole and half ratings share the maths
+    let stride = if props.half { 1 } else { 2 };
+    let steps = (1..=props.count * 2)
+        .filter(|step| step.is_multiple_of(stride))
+        .collect::<Vec<_>>();
+    let selected = props.value.map(|value| {
+        let value = value.clamp(0.0, f64::from(props.count));
+        let halves = (value * 2.0 / f64::from(stride)).round() as u32;
+        halves * stride
+    });
+    let half_class = |step: u32| match (props.half, step % 2) {
+        (false, _) => "",
+        (true, 1) => "mask-half-1",
+        (true, _) => "mask-half-2",
+    };
+    let count = props.count;
+
+    if props.read_only {
+        let label = match selected {
+            Some(step) => format!("{} out of {count}", step_value(step)),
+            None => "Not rated".to_string(),
+        };
+        return rsx!(
+            div {
+                class: "rating {size} {half}",
+                role: "img",
+                "aria-label": "{label}",
+                ..props.attributes,
+                for step in steps {
+                    div {
+                        class: "mask {mask} {half_class(step)} {color}",
+                        "aria-current": (selected == Some(step)).then_some("true"),
+                    }
+                }
+            }
+        );
+    }
+
+    rsx!(
+        div { class: "rating {size} {half}", ..props.attributes,
+            input {
+                r#type: "radio",
+                name: "{props.name}",
+                value: "0",
+                class: "rating-hidden",
+                "aria-label": "No rating",
+                checked: selected.is_none_or(|selected| selected == 0).then_some(true),
+            }
+            for step in steps {
+                input {
+                    r#type: "radio",
+                    name: "{props.name}",
+                    value: "{step_value(step)}",
+                    class: "mask {mask} {half_class(step)} {color}",
+                    "aria-label": "{step_value(step)} out of {count}",
+                    checked: (selected == Some(step)).then_some(true),
+                }
+            }
+        }
+    )
+}
+
+/// A number of half steps as the value posted, `3` or `3.5`.
+fn step_value(halves: u32) -> String {
+    if halves.is_multiple_of(2) {
+        (halves / 2).to_string()
+    } else {
+        format!("{}.5", halves / 2)
+    }
+}
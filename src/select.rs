@@ -1,50 +1,70 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum SelectSize {
-    #[default]
-    Default,
-    ExtraSmall,
-    Small,
-    Medium,
-    Large,
-}
+use crate::color::ColorPrefix;
+use crate::form::{FieldMessages, FieldState, text_attribute};
+use crate::size::{Size, SizePrefix};
 
-impl Display for SelectSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            SelectSize::Default => write!(f, ""),
-            SelectSize::ExtraSmall => write!(f, "select-xs"),
-            SelectSize::Small => write!(f, "select-sm"),
-            SelectSize::Medium => write!(f, "select-md"),
-            SelectSize::Large => write!(f, "select-lg"),
-        }
-    }
-}
+#[deprecated(since = "0.1.48", note = "use `Size` instead")]
+pub type SelectSize = Size;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct SelectProps {
     pub children: Element,
     /// Label text displayed above the select
     pub label: String,
+    /// Name, value, validation errors and hint from the server. The
+    /// `SelectOption` whose `value` matches the field's value is selected.
+    pub field: Option<FieldState>,
     #[props(default)]
-    pub select_size: SelectSize,
+    pub select_size: Size,
     /// All standard HTML select attributes (name, required, disabled, multiple, onchange, etc.)
     #[props(extends = select, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+impl ColorPrefix for SelectProps {
+    const COLOR_PREFIX: &'static str = "select";
+}
+
+impl SizePrefix for SelectProps {
+    const SIZE_PREFIX: &'static str = "select";
+}
+
+/// The submitted value of the enclosing `Select`, read by each `SelectOption`.
+#[derive(Clone)]
+struct SelectedValue(Option<String>);
+
 #[component]
 pub fn Select(props: SelectProps) -> Element {
-    let size = props.select_size.to_string();
+    use_hook(|| {
+        let explicit = text_attribute(&props.attributes, "value").map(str::to_string);
+        provide_context(SelectedValue(
+            explicit.or_else(|| props.field.as_ref().and_then(|field| field.value.clone())),
+        ))
+    });
+    let size = props.select_size.class::<SelectProps>();
+    let field_attributes = props
+        .field
+        .as_ref()
+        .map(|field| field.attributes_except(&props.attributes))
+        .unwrap_or_default();
+    let error = props
+        .field
+        .as_ref()
+        .map(FieldState::error_class::<SelectProps>)
+        .unwrap_or_default();
 
     rsx!(
         label { class: "flex flex-col gap-1",
             span { "{props.label}" }
-            select { class: "select select-bordered {size}", ..props.attributes, {props.children} }
+            select {
+                class: "select select-bordered {size} {error}",
+                ..field_attributes,
+                ..props.attributes,
+                {props.children}
+            }
+            FieldMessages { field: props.field }
         }
     )
 }
@@ -52,14 +72,28 @@ pub fn Select(props: SelectProps) -> Element {
 #[derive(Props, Clone, PartialEq)]
 pub struct OptionProps {
     pub children: Element,
-    /// All standard HTML option attributes (value, selected, disabled, etc.)
+    /// All standard HTML option attributes (value, selected, disabled, etc.).
+    /// Inside a `Select` with a `field` value, `selected` is replaced by
+    /// whether `value` matches it.
     #[props(extends = option, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn SelectOption(props: OptionProps) -> Element {
+    let Some(current) = try_consume_context::<SelectedValue>().and_then(|value| value.0) else {
+        return rsx!(
+            option { ..props.attributes,{props.children} }
+        );
+    };
+    let selected = text_attribute(&props.attributes, "value") == Some(current.as_str());
+    let attributes: Vec<Attribute> = props
+        .attributes
+        .into_iter()
+        .filter(|attribute| attribute.name != "selected")
+        .collect();
+
     rsx!(
-        option { ..props.attributes,{props.children} }
+        option { selected: selected.then_some(true), ..attributes, {props.children} }
     )
 }
@@ -0,0 +1,105 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The DaisyUI size scale shared by every sized component.
+///
+/// `Display` and `FromStr` use DaisyUI's own suffixes (`"xs"` to `"xl"`), so a
+/// whole form can be switched to a compact size from a single setting.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Size {
+    #[default]
+    Default,
+    ExtraSmall,
+    Small,
+    Medium,
+    Large,
+    ExtraLarge,
+}
+
+impl Size {
+    pub const ALL: [Size; 6] = [
+        Size::Default,
+        Size::ExtraSmall,
+        Size::Small,
+        Size::Medium,
+        Size::Large,
+        Size::ExtraLarge,
+    ];
+
+    // Spellings used by the old `BadgeSize` enum.
+    #[deprecated(since = "0.1.48", note = "use `Size::ExtraSmall` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const Xs: Size = Size::ExtraSmall;
+    #[deprecated(since = "0.1.48", note = "use `Size::Small` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const Sm: Size = Size::Small;
+    #[deprecated(since = "0.1.48", note = "use `Size::Medium` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const Md: Size = Size::Medium;
+    #[deprecated(since = "0.1.48", note = "use `Size::Large` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const Lg: Size = Size::Large;
+    #[deprecated(since = "0.1.48", note = "use `Size::ExtraLarge` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const Xl: Size = Size::ExtraLarge;
+
+    /// The DaisyUI modifier class for component `C`, e.g. `btn-sm`.
+    /// `Size::Default` produces an empty string.
+    pub fn class<C: SizePrefix>(&self) -> String {
+        self.class_with_prefix(C::SIZE_PREFIX)
+    }
+
+    /// The modifier class for an arbitrary prefix, e.g. `kbd` gives `kbd-sm`.
+    pub fn class_with_prefix(&self, prefix: &str) -> String {
+        match self {
+            Size::Default => String::new(),
+            size => format!("{prefix}-{size}"),
+        }
+    }
+}
+
+impl Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Size::Default => write!(f, ""),
+            Size::ExtraSmall => write!(f, "xs"),
+            Size::Small => write!(f, "sm"),
+            Size::Medium => write!(f, "md"),
+            Size::Large => write!(f, "lg"),
+            Size::ExtraLarge => write!(f, "xl"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseSizeError(pub String);
+
+impl Display for ParseSizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown size: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseSizeError {}
+
+impl FromStr for Size {
+    type Err = ParseSizeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "" | "default" => Ok(Size::Default),
+            "xs" | "extra-small" => Ok(Size::ExtraSmall),
+            "sm" | "small" => Ok(Size::Small),
+            "md" | "medium" => Ok(Size::Medium),
+            "lg" | "large" => Ok(Size::Large),
+            "xl" | "extra-large" => Ok(Size::ExtraLarge),
+            _ => Err(ParseSizeError(s.to_string())),
+        }
+    }
+}
+
+/// Implemented by each sized component to name the prefix DaisyUI uses for
+/// its size modifiers (`btn`, `input`, `loading`, ...).
+pub trait SizePrefix {
+    const SIZE_PREFIX: &'static str;
+}
@@ -0,0 +1,62 @@
+//! Snapshot testing for rendered HTML.
+//!
+//! Snapshots are plain `.html` files. A missing or changed snapshot fails the
+//! test; set `DAISY_RSX_UPDATE_SNAPSHOTS=1` to write new or changed output.
+//!
+//! ```rust,no_run
+//! use daisy_rsx::snapshot::assert_snapshot;
+//! use daisy_rsx::*;
+//! use dioxus::prelude::*;
+//!
+//! assert_snapshot(
+//!     concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots"),
+//!     "primary_button",
+//!     rsx! {
+//!         Button { button_scheme: Color::Primary, "Save" }
+//!     },
+//! );
+//! ```
+use std::fs;
+use std::path::Path;
+
+use dioxus::prelude::*;
+
+use crate::ssr::render_fragment;
+
+/// Set this environment variable to write snapshots that are missing or don't
+/// match.
+pub const UPDATE_ENV: &str = "DAISY_RSX_UPDATE_SNAPSHOTS";
+
+/// Render `element` and compare it with `<dir>/<name>.html`.
+#[track_caller]
+pub fn assert_snapshot(dir: impl AsRef<Path>, name: &str, element: Element) {
+    assert_html_snapshot(dir, name, &render_fragment(element));
+}
+
+/// Compare already rendered HTML with `<dir>/<name>.html`.
+#[track_caller]
+pub fn assert_html_snapshot(dir: impl AsRef<Path>, name: &str, html: &str) {
+    let path = dir.as_ref().join(format!("{name}.html"));
+    let actual = format!("{}\n", html.trim_end());
+    let update = std::env::var_os(UPDATE_ENV).is_some_and(|v| !v.is_empty() && v != "0");
+
+    match fs::read_to_string(&path) {
+        Ok(expected) if expected == actual => {}
+        _ if update => {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).expect("create snapshot directory");
+            }
+            fs::write(&path, actual).expect("write snapshot");
+        }
+        Ok(expected) => panic!(
+            "snapshot {} does not match, rerun with {UPDATE_ENV}=1 to accept\n\
+             --- expected\n{expected}\n+++ actual\n{actual}",
+            path.display()
+        ),
+        Err(_) => panic!(
+            "snapshot {} is missing, rerun with {UPDATE_ENV}=1 to write it\n\
+             +++ actual\n{actual}",
+            path.display()
+        ),
+    }
+}
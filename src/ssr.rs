@@ -0,0 +1,150 @@
+//! Server side rendering helpers built on `dioxus-ssr`.
+//!
+//! ```rust
+//! use daisy_rsx::ssr;
+//! use dioxus::prelude::*;
+//!
+//! let html = ssr::render_fragment(rsx! {
+//!     p { "Saved" }
+//! });
+//! assert_eq!(html, "<p>Saved</p>");
+//! ```
+use std::fmt;
+use std::io;
+
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::prelude::*;
+use dioxus_ssr::Renderer;
+
+use crate::app_layout::{AppLayout, AppLayoutContent, AppLayoutProps};
+
+const DOCTYPE: &str = "<!DOCTYPE html>";
+
+/// Render a full page wrapped in `AppLayout`, including the doctype and the
+/// `<html>` element with `lang` and `data-theme`. This, [`write_page`] and
+/// [`write_page_io`] are the only ways to render a complete page.
+pub fn render_page(props: AppLayoutProps) -> String {
+    let mut buf = String::new();
+    write_page(&mut buf, props).expect("writing to a String cannot fail");
+    buf
+}
+
+/// Render only the title and main content of a page, for requests that
+/// swap `#main-content` such as htmx navigation. The page keeps the `lang`,
+/// `data-theme` and head tags it was first rendered with.
+pub fn render_content(props: AppLayoutProps) -> String {
+    let mut dom = VirtualDom::new_with_props(AppLayoutContent, props);
+    build(&mut dom);
+    Renderer::new().render(&dom)
+}
+
+/// Render an element on its own, e.g. for a partial swap.
+pub fn render_fragment(element: Element) -> String {
+    let mut buf = String::new();
+    write_fragment(&mut buf, element).expect("writing to a String cannot fail");
+    buf
+}
+
+/// Render an element as a Server-Sent Events message named `event`, for
+/// swapping in with htmx's `sse` extension. See [`crate::htmx::Sse`].
+pub fn render_sse_event(event: &str, element: Element) -> String {
+    let html = render_fragment(element);
+    let mut message = format!("event: {event}\n");
+    for line in html.split('\n') {
+        message.push_str("data: ");
+        message.push_str(line);
+        message.push('\n');
+    }
+    message.push('\n');
+    message
+}
+
+/// Write a full page wrapped in `AppLayout` into `out`.
+pub fn write_page<W: fmt::Write + ?Sized>(out: &mut W, props: AppLayoutProps) -> fmt::Result {
+    let theme = props.theme.clone();
+    let lang = props.meta.lang.clone();
+    let mut dom = VirtualDom::new_with_props(AppLayout, props);
+    build(&mut dom);
+
+    out.write_str(DOCTYPE)?;
+    out.write_str("<html")?;
+    if let Some(lang) = lang {
+        write!(out, " lang=\"{}\"", escape_attribute(&lang))?;
+    }
+    if let Some(theme) = theme {
+        write!(out, " data-theme=\"{}\"", escape_attribute(theme.name()))?;
+    }
+    out.write_str(">")?;
+    Renderer::new().render_to(out, &dom)?;
+    out.write_str("</html>")
+}
+
+/// Write an element on its own into `out`.
+pub fn write_fragment<W: fmt::Write + ?Sized>(out: &mut W, element: Element) -> fmt::Result {
+    fn fragment(element: Element) -> Element {
+        element
+    }
+    let mut dom = VirtualDom::new_with_props(fragment, element);
+    build(&mut dom);
+    Renderer::new().render_to(out, &dom)
+}
+
+/// Like [`write_page`] but for byte sinks such as sockets or response bodies.
+pub fn write_page_io<W: io::Write>(out: W, props: AppLayoutProps) -> io::Result<()> {
+    let mut adapter = IoAdapter::new(out);
+    let result = write_page(&mut adapter, props);
+    adapter.finish(result)
+}
+
+/// Like [`write_fragment`] but for byte sinks such as sockets or response bodies.
+pub fn write_fragment_io<W: io::Write>(out: W, element: Element) -> io::Result<()> {
+    let mut adapter = IoAdapter::new(out);
+    let result = write_fragment(&mut adapter, element);
+    adapter.finish(result)
+}
+
+/// Renders `dom`, then re-renders the components that changed state while
+/// it rendered, such as a `NavSubGroup` opened by a selected item inside it.
+fn build(dom: &mut VirtualDom) {
+    dom.rebuild_in_place();
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+/// Escapes a value for use inside a double quoted attribute.
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Forwards `fmt::Write` calls to an `io::Write`, keeping the first io error
+/// since `fmt::Error` carries no detail.
+struct IoAdapter<W> {
+    inner: W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> IoAdapter<W> {
+    fn new(inner: W) -> Self {
+        Self { inner, error: None }
+    }
+
+    fn finish(mut self, result: fmt::Result) -> io::Result<()> {
+        match (result, self.error.take()) {
+            (_, Some(err)) => Err(err),
+            (Err(_), None) => Err(io::Error::other("formatting error while rendering")),
+            (Ok(()), None) => self.inner.flush(),
+        }
+    }
+}
+
+impl<W: io::Write> fmt::Write for IoAdapter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
@@ -0,0 +1,261 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StatsDirection {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl Display for StatsDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatsDirection::Horizontal => write!(f, "stats-horizontal"),
+            StatsDirection::Vertical => write!(f, "stats-vertical"),
+        }
+    }
+}
+
+/// Which way a stat is moving, shown as an arrow in success or error color.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Trend {
+    #[default]
+    Up,
+    Down,
+}
+
+impl Trend {
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            Trend::Up => "↗︎",
+            Trend::Down => "↘︎",
+        }
+    }
+
+    /// `text-success` when moving the `good` way, otherwise `text-error`.
+    pub fn color_class(&self, good: Trend) -> &'static str {
+        if *self == good {
+            "text-success"
+        } else {
+            "text-error"
+        }
+    }
+}
+
+/// The data for one [`Stat`], so a row of them can come from a query.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Metric {
+    pub title: String,
+    pub value: String,
+    pub description: Option<String>,
+    pub trend: Option<Trend>,
+    /// The direction that's an improvement, `Down` for costs or churn
+    pub good: Trend,
+    pub sparkline: Vec<f64>,
+    pub figure: Option<Element>,
+}
+
+impl Metric {
+    pub fn new(title: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            value: value.into(),
+            description: None,
+            trend: None,
+            good: Trend::Up,
+            sparkline: Vec::new(),
+            figure: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn trend(mut self, trend: Trend) -> Self {
+        self.trend = Some(trend);
+        self
+    }
+
+    pub fn good(mut self, good: Trend) -> Self {
+        self.good = good;
+        self
+    }
+
+    pub fn sparkline(mut self, values: Vec<f64>) -> Self {
+        self.sparkline = values;
+        self
+    }
+
+    pub fn figure(mut self, figure: Element) -> Self {
+        self.figure = Some(figure);
+        self
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct StatsProps {
+    /// Rendered as a `Stat` each, before any children
+    #[props(default)]
+    pub metrics: Vec<Metric>,
+    #[props(default)]
+    pub children: Element,
+    #[props(default)]
+    pub direction: StatsDirection,
+    /// All standard HTML div attributes (id, class, etc.)
+    #[props(extends = div, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// A row or column of KPIs.
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
+/// Stats {
+///     class: "shadow",
+///     metrics: vec![
+///         Metric::new("Revenue", "$89,400")
+///             .description("21% more than last month")
+///             .trend(Trend::Up)
+///             .sparkline(vec![3.0, 5.0, 4.0, 8.0]),
+///         Metric::new("Churn", "2.1%")
+///             .trend(Trend::Down)
+///             .good(Trend::Down),
+///     ],
+/// }
+/// # }}
+/// ```
+#[component]
+pub fn Stats(props: StatsProps) -> Element {
+    rsx!(
+        div { class: "stats {props.direction}", ..props.attributes,
+            for metric in props.metrics.iter() {
+                Stat {
+                    title: metric.title.clone(),
+                    value: metric.value.clone(),
+                    description: metric.description.clone(),
+                    trend: metric.trend,
+                    good: metric.good,
+                    sparkline: metric.sparkline.clone(),
+                    figure: metric.figure.clone(),
+                }
+            }
+            {props.children}
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct StatProps {
+    #[props(into)]
+    pub title: String,
+    #[props(into)]
+    pub value: String,
+    pub description: Option<String>,
+    /// Colors the description and sparkline, and adds an arrow before the
+    /// description
+    pub trend: Option<Trend>,
+    /// The direction that's an improvement and shown in success color
+    #[props(default)]
+    pub good: Trend,
+    /// Values drawn as a small line chart under the value
+    #[props(default)]
+    pub sparkline: Vec<f64>,
+    /// An icon or avatar shown beside the stat
+    pub figure: Option<Element>,
+    /// All standard HTML div attributes (id, class, etc.)
+    #[props(extends = div, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn Stat(props: StatProps) -> Element {
+    let trend_class = props
+        .trend
+        .map(|trend| trend.color_class(props.good))
+        .unwrap_or_default();
+    // The arrow is only followed by a space when there's text after it
+    let description = match (props.trend, props.description) {
+        (Some(trend), Some(description)) => Some(format!("{} {description}", trend.arrow())),
+        (Some(trend), None) => Some(trend.arrow().to_string()),
+        (None, description) => description,
+    };
+
+    rsx!(
+        div { class: "stat", ..props.attributes,
+            if let Some(figure) = props.figure {
+                div { class: "stat-figure", {figure} }
+            }
+            div { class: "stat-title", "{props.title}" }
+            div { class: "stat-value", "{props.value}" }
+            if !props.sparkline.is_empty() {
+                div { class: "{trend_class}",
+                    Sparkline { values: props.sparkline }
+                }
+            }
+            if let Some(description) = description {
+                div { class: "stat-desc {trend_class}", "{description}" }
+            }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SparklineProps {
+    pub values: Vec<f64>,
+    #[props(default = 100)]
+    pub width: u32,
+    #[props(default = 24)]
+    pub height: u32,
+}
+
+/// A small inline SVG line chart, drawn in the current text color.
+#[component]
+pub fn Sparkline(props: SparklineProps) -> Element {
+    let points = sparkline_points(&props.values, props.width, props.height);
+
+    rsx!(
+        svg {
+            xmlns: "http://www.w3.org/2000/svg",
+            view_box: "0 0 {props.width} {props.height}",
+            width: "{props.width}",
+            height: "{props.height}",
+            fill: "none",
+            stroke: "currentColor",
+            stroke_width: "2",
+            "aria-hidden": "true",
+            polyline { points }
+        }
+    )
+}
+
+/// The `points` of a polyline scaling `values` to fill `width` by `height`,
+/// with the largest value at the top. `NaN` and infinite values are skipped.
+pub fn sparkline_points(values: &[f64], width: u32, height: u32) -> String {
+    let (width, height) = (f64::from(width), f64::from(height));
+    let values: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let step = width / (values.len().max(2) - 1) as f64;
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let y = if max > min {
+                height - (value - min) / (max - min) * height
+            } else {
+                height / 2.0
+            };
+            format!("{:.1},{:.1}", index as f64 * step, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
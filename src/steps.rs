@@ -0,0 +1,299 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+
+use crate::button::{Button, ButtonStyle};
+use crate::color::{Color, ColorPrefix};
+use crate::htmx::Hx;
+
+/// Name of the hidden input carrying a [`Wizard`]'s current step index.
+pub const WIZARD_STEP_FIELD: &str = "wizard_step";
+/// Name of the [`Wizard`] button that submitted the form, see [`WizardAction`].
+pub const WIZARD_ACTION_FIELD: &str = "wizard_action";
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StepsDirection {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl Display for StepsDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepsDirection::Horizontal => write!(f, "steps-horizontal"),
+            StepsDirection::Vertical => write!(f, "steps-vertical"),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct StepsProps {
+    pub children: Element,
+    #[props(default)]
+    pub direction: StepsDirection,
+    /// All standard HTML list attributes (id, class, etc.)
+    #[props(extends = ul, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// A row or column of [`Step`]s showing progress through a process.
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
+/// Steps {
+///     Step { step_color: Color::Primary, "Register" }
+///     Step { step_color: Color::Primary, "Choose plan" }
+///     Step { "Purchase" }
+/// }
+/// # }}
+/// ```
+#[component]
+pub fn Steps(props: StepsProps) -> Element {
+    rsx!(
+        ul { class: "steps {props.direction}", ..props.attributes, {props.children} }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct StepProps {
+    pub children: Element,
+    /// Color the step to mark it as reached
+    #[props(default)]
+    pub step_color: Color,
+    /// Shown in the circle instead of the step number, such as `✓` or `★`
+    pub content: Option<String>,
+    /// All standard HTML list item attributes (id, class, etc.)
+    #[props(extends = li, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl ColorPrefix for StepProps {
+    const COLOR_PREFIX: &'static str = "step";
+}
+
+#[component]
+pub fn Step(props: StepProps) -> Element {
+    let color = props.step_color.class::<StepProps>();
+
+    rsx!(
+        li {
+            class: "step {color}",
+            "data-content": props.content,
+            ..props.attributes,
+            {props.children}
+        }
+    )
+}
+
+/// Which [`Wizard`] button submitted the form, sent as [`WIZARD_ACTION_FIELD`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WizardAction {
+    Back,
+    Next,
+    Finish,
+}
+
+impl Display for WizardAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WizardAction::Back => write!(f, "back"),
+            WizardAction::Next => write!(f, "next"),
+            WizardAction::Finish => write!(f, "finish"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseWizardActionError(pub String);
+
+impl Display for ParseWizardActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown wizard action: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseWizardActionError {}
+
+impl FromStr for WizardAction {
+    type Err = ParseWizardActionError;
+
+    /// The action for a submitted `wizard_action` value.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "back" => Ok(WizardAction::Back),
+            "next" => Ok(WizardAction::Next),
+            "finish" => Ok(WizardAction::Finish),
+            _ => Err(ParseWizardActionError(s.to_string())),
+        }
+    }
+}
+
+/// One step of a [`Wizard`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct WizardStep {
+    pub title: String,
+    /// Shown in the step's circle instead of its number
+    pub content: Option<String>,
+    /// Names of the inputs in `body`, whose previous answers the body shows
+    /// itself instead of carrying them as hidden inputs
+    pub fields: Vec<String>,
+    pub body: Element,
+}
+
+impl WizardStep {
+    pub fn new(title: impl Into<String>, body: Element) -> Self {
+        Self {
+            title: title.into(),
+            content: None,
+            fields: Vec::new(),
+            body,
+        }
+    }
+
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    pub fn field(mut self, name: impl Into<String>) -> Self {
+        self.fields.push(name.into());
+        self
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct WizardProps {
+    pub steps: Vec<WizardStep>,
+    /// Index of the step to show
+    pub current: usize,
+    /// Where the form posts. It sends the current step as
+    /// [`WIZARD_STEP_FIELD`] and the button pressed as [`WIZARD_ACTION_FIELD`].
+    pub action: String,
+    /// Every answer so far. Those not on the current step are sent on as
+    /// hidden inputs, so the last step submits them all.
+    #[props(default)]
+    pub answers: Vec<(String, String)>,
+    /// Color of the steps reached and the Next and Finish buttons
+    #[props(default = Color::Primary)]
+    pub step_color: Color,
+    #[props(default)]
+    pub direction: StepsDirection,
+    #[props(into, default = "Back".to_string())]
+    pub back_label: String,
+    #[props(into, default = "Next".to_string())]
+    pub next_label: String,
+    #[props(into, default = "Finish".to_string())]
+    pub finish_label: String,
+    /// Submit the form with htmx
+    pub hx: Option<Hx>,
+}
+
+/// A multi-step form whose state lives on the server: each submit posts the
+/// current step and every answer so far, and the server renders the next
+/// step.
+///
+/// # Example
+/// ```rust
+/// # use daisy_rsx::*;
+/// # use dioxus::prelude::*;
+/// # fn app() -> Element { rsx! {
+/// Wizard {
+///     action: "/onboarding",
+///     current: 1,
+///     answers: vec![("company".to_string(), "Acme".to_string())],
+///     steps: vec![
+///         WizardStep::new("Company", rsx! {
+///             Input { label: "Company", name: "company", value: "Acme" }
+///         })
+///         .field("company"),
+///         WizardStep::new("Plan", rsx! { Input { label: "Plan", name: "plan" } }).field("plan"),
+///         WizardStep::new("Confirm", rsx! { p { "All done?" } }).content("✓"),
+///     ],
+/// }
+/// # }}
+/// ```
+#[component]
+pub fn Wizard(props: WizardProps) -> Element {
+    let Some(last) = props.steps.len().checked_sub(1) else {
+        return rsx!();
+    };
+    let current = props.current.min(last);
+    let step = &props.steps[current];
+    let hidden = props
+        .answers
+        .iter()
+        .filter(|(name, _)| !step.fields.contains(name))
+        .collect::<Vec<_>>();
+    let hx = props
+        .hx
+        .as_ref()
+        .map(|hx| hx.form_attributes(&props.action))
+        .unwrap_or_default();
+
+    rsx!(
+        form {
+            class: "flex flex-col gap-6",
+            action: "{props.action}",
+            method: "post",
+            ..hx,
+            Steps { direction: props.direction,
+                for (index , step) in props.steps.iter().enumerate() {
+                    Step {
+                        step_color: if index <= current { props.step_color } else { Color::Default },
+                        content: step.content.clone(),
+                        "aria-current": (index == current).then_some("step"),
+                        "{step.title}"
+                    }
+                }
+            }
+            input {
+                r#type: "hidden",
+                name: WIZARD_STEP_FIELD,
+                value: "{current}",
+            }
+            for (name , value) in hidden {
+                input { r#type: "hidden", name: "{name}", value: "{value}" }
+            }
+            div { {step.body.clone()} }
+            // Next or Finish comes first so pressing Enter in a field submits
+            // it rather than Back; `flex-row-reverse` still shows Back on the left.
+            div { class: "flex flex-row-reverse justify-between gap-2",
+                if current < last {
+                    Button {
+                        button_scheme: props.step_color,
+                        r#type: "submit",
+                        name: WIZARD_ACTION_FIELD,
+                        value: "{WizardAction::Next}",
+                        "{props.next_label}"
+                    }
+                } else {
+                    Button {
+                        button_scheme: props.step_color,
+                        r#type: "submit",
+                        name: WIZARD_ACTION_FIELD,
+                        value: "{WizardAction::Finish}",
+                        "{props.finish_label}"
+                    }
+                }
+                if current > 0 {
+                    Button {
+                        button_style: ButtonStyle::Ghost,
+                        r#type: "submit",
+                        name: WIZARD_ACTION_FIELD,
+                        value: "{WizardAction::Back}",
+                        formnovalidate: true,
+                        "{props.back_label}"
+                    }
+                } else {
+                    span {}
+                }
+            }
+        }
+    )
+}
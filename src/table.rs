@@ -0,0 +1,352 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+
+use crate::blank_slate::BlankSlate;
+use crate::query::{param, with_params};
+use crate::size::{Size, SizePrefix};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TableProps {
+    pub children: Element,
+    /// Alternate row background colors
+    #[props(default)]
+    pub zebra: bool,
+    /// Keep the head and foot rows visible while scrolling
+    #[props(default)]
+    pub pin_rows: bool,
+    /// Keep `th` cells visible while scrolling horizontally
+    #[props(default)]
+    pub pin_cols: bool,
+    #[props(default)]
+    pub table_size: Size,
+    /// All standard HTML table attributes (id, style, etc.)
+    #[props(extends = table, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl SizePrefix for TableProps {
+    const SIZE_PREFIX: &'static str = "table";
+}
+
+#[component]
+pub fn Table(props: TableProps) -> Element {
+    let zebra = if props.zebra { "table-zebra" } else { "" };
+    let pin_rows = if props.pin_rows { "table-pin-rows" } else { "" };
+    let pin_cols = if props.pin_cols { "table-pin-cols" } else { "" };
+    let size = props.table_size.class::<TableProps>();
+
+    rsx!(
+        div { class: "overflow-x-auto",
+            table {
+                class: "table {zebra} {pin_rows} {pin_cols} {size}",
+                ..props.attributes,
+                {props.children}
+            }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TableHeadProps {
+    pub children: Element,
+    /// All standard HTML thead attributes (id, style, etc.)
+    #[props(extends = thead, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn TableHead(props: TableHeadProps) -> Element {
+    rsx!(
+        thead { ..props.attributes, {props.children} }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TableBodyProps {
+    pub children: Element,
+    /// All standard HTML tbody attributes (id, style, etc.)
+    #[props(extends = tbody, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn TableBody(props: TableBodyProps) -> Element {
+    rsx!(
+        tbody { ..props.attributes, {props.children} }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TableRowProps {
+    pub children: Element,
+    /// Highlight the row on hover
+    #[props(default)]
+    pub hover: bool,
+    /// All standard HTML tr attributes (id, style, onclick, etc.)
+    #[props(extends = tr, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn TableRow(props: TableRowProps) -> Element {
+    let hover = if props.hover { "row-hover" } else { "" };
+
+    rsx!(
+        tr { class: "{hover}", ..props.attributes, {props.children} }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TableCellProps {
+    pub children: Element,
+    /// Render a `th` instead of a `td`
+    #[props(default)]
+    pub header: bool,
+    /// All standard HTML td attributes (colspan, rowspan, headers, etc.)
+    #[props(extends = td, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn TableCell(props: TableCellProps) -> Element {
+    if props.header {
+        rsx!(
+            th { ..props.attributes, {props.children} }
+        )
+    } else {
+        rsx!(
+            td { ..props.attributes, {props.children} }
+        )
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn reversed(&self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+
+    fn aria(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+        }
+    }
+}
+
+impl Display for SortDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortDirection::Ascending => write!(f, "asc"),
+            SortDirection::Descending => write!(f, "desc"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseSortDirectionError(pub String);
+
+impl Display for ParseSortDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown sort direction: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseSortDirectionError {}
+
+impl FromStr for SortDirection {
+    type Err = ParseSortDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "asc" | "ascending" => Ok(SortDirection::Ascending),
+            "desc" | "descending" => Ok(SortDirection::Descending),
+            _ => Err(ParseSortDirectionError(s.to_string())),
+        }
+    }
+}
+
+/// The sort order the server applied to the rows, read back from the
+/// `sort` and `order` query parameters the header links produce.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sort {
+    pub key: String,
+    pub direction: SortDirection,
+}
+
+impl Sort {
+    /// Read the sort from a URL or query string such as
+    /// `/users?sort=name&order=desc`. Returns `None` without a `sort`
+    /// parameter; a missing or unknown `order` sorts ascending.
+    pub fn from_query(query: &str) -> Option<Sort> {
+        let key = param(query, "sort").filter(|key| !key.is_empty())?;
+        let direction = param(query, "order")
+            .and_then(|order| order.parse().ok())
+            .unwrap_or_default();
+        Some(Sort { key, direction })
+    }
+}
+
+/// One column of a [`DataTable`].
+pub struct Column<T> {
+    pub header: String,
+    pub cell: Rc<dyn Fn(&T) -> Element>,
+    /// The value sent as `sort` when the header is clicked; `None` disables sorting.
+    pub sort_key: Option<String>,
+}
+
+impl<T> Column<T> {
+    pub fn new(header: impl Into<String>, cell: impl Fn(&T) -> Element + 'static) -> Self {
+        Self {
+            header: header.into(),
+            cell: Rc::new(cell),
+            sort_key: None,
+        }
+    }
+
+    pub fn sortable(mut self, sort_key: impl Into<String>) -> Self {
+        self.sort_key = Some(sort_key.into());
+        self
+    }
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            cell: self.cell.clone(),
+            sort_key: self.sort_key.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for Column<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header
+            && self.sort_key == other.sort_key
+            && Rc::ptr_eq(&self.cell, &other.cell)
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DataTableProps<T: Clone + PartialEq + 'static> {
+    pub rows: Vec<T>,
+    pub columns: Vec<Column<T>>,
+    /// The current page URL; sort links keep its other query parameters
+    #[props(into, default)]
+    pub sort_url: String,
+    /// The sort currently applied to `rows`
+    pub sort: Option<Sort>,
+    #[props(default)]
+    pub zebra: bool,
+    #[props(default)]
+    pub pin_rows: bool,
+    /// Pin the first column, which is rendered as `th` cells so it stays
+    /// visible while scrolling horizontally
+    #[props(default)]
+    pub pin_cols: bool,
+    #[props(default)]
+    pub table_size: Size,
+    /// Shown in a `BlankSlate` when there are no rows
+    #[props(into)]
+    pub empty_heading: String,
+    #[props(into, default)]
+    pub empty_description: String,
+    #[props(into, default)]
+    pub empty_visual: String,
+}
+
+#[component]
+pub fn DataTable<T: Clone + PartialEq + 'static>(props: DataTableProps<T>) -> Element {
+    if props.rows.is_empty() {
+        return rsx!(BlankSlate {
+            heading: props.empty_heading,
+            visual: props.empty_visual,
+            description: props.empty_description,
+        });
+    }
+
+    rsx!(
+        Table {
+            zebra: props.zebra,
+            pin_rows: props.pin_rows,
+            pin_cols: props.pin_cols,
+            table_size: props.table_size,
+            TableHead {
+                tr {
+                    for column in &props.columns {
+                        SortableHeader {
+                            header: column.header.clone(),
+                            sort_key: column.sort_key.clone(),
+                            sort_url: props.sort_url.clone(),
+                            sort: props.sort.clone(),
+                        }
+                    }
+                }
+            }
+            TableBody {
+                for row in &props.rows {
+                    tr {
+                        for (index, column) in props.columns.iter().enumerate() {
+                            TableCell { header: props.pin_cols && index == 0, {(column.cell)(row)} }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn SortableHeader(
+    header: String,
+    sort_key: Option<String>,
+    sort_url: String,
+    sort: Option<Sort>,
+) -> Element {
+    let Some(sort_key) = sort_key else {
+        return rsx!(
+            th { "{header}" }
+        );
+    };
+
+    let current = sort
+        .filter(|sort| sort.key == sort_key)
+        .map(|sort| sort.direction);
+    let next = current
+        .map(|direction| direction.reversed())
+        .unwrap_or_default();
+    let href = with_params(
+        &sort_url,
+        &[("sort", &sort_key), ("order", &next.to_string())],
+    );
+    let indicator = match current {
+        Some(SortDirection::Ascending) => " ▲",
+        Some(SortDirection::Descending) => " ▼",
+        None => "",
+    };
+
+    rsx!(
+        th { "aria-sort": current.map(|direction| direction.aria()),
+            a { class: "link link-hover", href: "{href}",
+                "{header}"
+                if current.is_some() {
+                    span { "aria-hidden": "true", "{indicator}" }
+                }
+            }
+        }
+    )
+}
@@ -1,49 +1,64 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
-pub enum TextAreaSize {
-    #[default]
-    Default,
-    ExtraSmall,
-    Small,
-    Medium,
-    Large,
-}
+use crate::color::ColorPrefix;
+use crate::form::{FieldMessages, FieldState};
+use crate::size::{Size, SizePrefix};
 
-impl Display for TextAreaSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            TextAreaSize::Default => write!(f, ""),
-            TextAreaSize::ExtraSmall => write!(f, "textarea-xs"),
-            TextAreaSize::Small => write!(f, "textarea-sm"),
-            TextAreaSize::Medium => write!(f, "textarea-md"),
-            TextAreaSize::Large => write!(f, "textarea-lg"),
-        }
-    }
-}
+#[deprecated(since = "0.1.48", note = "use `Size` instead")]
+pub type TextAreaSize = Size;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct TextAreaProps {
     /// Label text displayed above the textarea
     pub label: String,
+    /// Name, value, validation errors and hint from the server
+    pub field: Option<FieldState>,
     #[props(default)]
-    pub textarea_size: TextAreaSize,
+    pub textarea_size: Size,
     /// All standard HTML textarea attributes (name, rows, placeholder, oninput, etc.)
     #[props(extends = textarea, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+impl ColorPrefix for TextAreaProps {
+    const COLOR_PREFIX: &'static str = "textarea";
+}
+
+impl SizePrefix for TextAreaProps {
+    const SIZE_PREFIX: &'static str = "textarea";
+}
+
 #[component]
 pub fn TextArea(props: TextAreaProps) -> Element {
-    let size = props.textarea_size.to_string();
+    let size = props.textarea_size.class::<TextAreaProps>();
+    let field_attributes = props
+        .field
+        .as_ref()
+        .map(|field| field.attributes_except(&props.attributes))
+        .unwrap_or_default();
+    let error = props
+        .field
+        .as_ref()
+        .map(FieldState::error_class::<TextAreaProps>)
+        .unwrap_or_default();
+    let value = props
+        .field
+        .as_ref()
+        .and_then(|field| field.value_except(&props.attributes));
 
     rsx!(
         label { class: "flex flex-col gap-1",
             span { "{props.label}" }
-            textarea { class: "textarea textarea-bordered {size}", ..props.attributes }
+            textarea {
+                class: "textarea textarea-bordered {size} {error}",
+                ..field_attributes,
+                ..props.attributes,
+                if let Some(value) = value {
+                    "{value}"
+                }
+            }
+            FieldMessages { field: props.field }
         }
     )
 }
@@ -0,0 +1,188 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+
+macro_rules! themes {
+    ($($(#[$meta:meta])* $variant:ident => $name:literal,)*) => {
+        /// The built-in DaisyUI themes, plus `Custom` for themes defined in
+        /// your own CSS with `@plugin "daisyui/theme"`.
+        #[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum Theme {
+            $($(#[$meta])* $variant,)*
+            Custom(String),
+        }
+
+        impl Theme {
+            /// Every built-in theme, in the order DaisyUI lists them.
+            pub const BUILT_IN: &'static [Theme] = &[$(Theme::$variant,)*];
+
+            /// The value used for `data-theme` and `theme-controller` inputs.
+            pub fn name(&self) -> &str {
+                match self {
+                    $(Theme::$variant => $name,)*
+                    Theme::Custom(name) => name,
+                }
+            }
+        }
+
+        impl FromStr for Theme {
+            type Err = std::convert::Infallible;
+
+            /// Unknown names parse as `Theme::Custom`.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s.trim() {
+                    $($name => Theme::$variant,)*
+                    other => Theme::Custom(other.to_string()),
+                })
+            }
+        }
+    };
+}
+
+themes! {
+    #[default]
+    Light => "light",
+    Dark => "dark",
+    Cupcake => "cupcake",
+    Bumblebee => "bumblebee",
+    Emerald => "emerald",
+    Corporate => "corporate",
+    Synthwave => "synthwave",
+    Retro => "retro",
+    Cyberpunk => "cyberpunk",
+    Valentine => "valentine",
+    Halloween => "halloween",
+    Garden => "garden",
+    Forest => "forest",
+    Aqua => "aqua",
+    Lofi => "lofi",
+    Pastel => "pastel",
+    Fantasy => "fantasy",
+    Wireframe => "wireframe",
+    Black => "black",
+    Luxury => "luxury",
+    Dracula => "dracula",
+    Cmyk => "cmyk",
+    Autumn => "autumn",
+    Business => "business",
+    Acid => "acid",
+    Lemonade => "lemonade",
+    Night => "night",
+    Coffee => "coffee",
+    Winter => "winter",
+    Dim => "dim",
+    Nord => "nord",
+    Sunset => "sunset",
+    Caramellatte => "caramellatte",
+    Abyss => "abyss",
+    Silk => "silk",
+}
+
+impl Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Theme {
+    /// The theme name with its first letter capitalised, for labels.
+    pub fn label(&self) -> String {
+        let name = self.name();
+        let mut chars = name.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ThemeControllerStyle {
+    /// A row of radio buttons styled as a `join` of buttons
+    #[default]
+    Radio,
+    /// A single toggle switching between the page theme and the first theme
+    Toggle,
+    /// A dropdown listing each theme
+    Dropdown,
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ThemeControllerProps {
+    /// The themes to offer
+    pub themes: Vec<Theme>,
+    /// The theme that should start out checked
+    pub current: Option<Theme>,
+    #[props(default)]
+    pub controller_style: ThemeControllerStyle,
+    /// Radio group name, change it when a page has more than one controller
+    #[props(into, default = "theme".to_string())]
+    pub name: String,
+    /// Text for the dropdown button and the toggle's accessible label
+    #[props(into, default = "Theme".to_string())]
+    pub label: String,
+    /// All standard HTML div attributes (id, style, etc.)
+    #[props(extends = div, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+/// Switches the page theme with DaisyUI's CSS-only `theme-controller`.
+#[component]
+pub fn ThemeController(props: ThemeControllerProps) -> Element {
+    let checked = |theme: &Theme| props.current.as_ref() == Some(theme);
+
+    match props.controller_style {
+        ThemeControllerStyle::Radio => rsx!(
+            div { class: "join", ..props.attributes,
+                for theme in &props.themes {
+                    input {
+                        r#type: "radio",
+                        class: "join-item btn theme-controller",
+                        name: "{props.name}",
+                        value: "{theme}",
+                        "aria-label": theme.label(),
+                        checked: checked(theme),
+                    }
+                }
+            }
+        ),
+        ThemeControllerStyle::Toggle => {
+            let theme = props.themes.first().cloned().unwrap_or(Theme::Dark);
+            rsx!(
+                label { class: "flex cursor-pointer items-center gap-2", ..props.attributes,
+                    input {
+                        r#type: "checkbox",
+                        class: "toggle theme-controller",
+                        value: "{theme}",
+                        "aria-label": "{props.label}",
+                        checked: checked(&theme),
+                    }
+                    span { "{theme.label()}" }
+                }
+            )
+        }
+        ThemeControllerStyle::Dropdown => rsx!(
+            div { class: "dropdown", ..props.attributes,
+                div { tabindex: "0", role: "button", class: "btn m-1", "{props.label}" }
+                ul {
+                    tabindex: "0",
+                    class: "dropdown-content z-[1] menu p-2 shadow bg-base-100 rounded-box w-52",
+                    for theme in &props.themes {
+                        li {
+                            input {
+                                r#type: "radio",
+                                class: "theme-controller w-full btn btn-sm btn-block btn-ghost justify-start",
+                                name: "{props.name}",
+                                value: "{theme}",
+                                "aria-label": theme.label(),
+                                checked: checked(theme),
+                            }
+                        }
+                    }
+                }
+            }
+        ),
+    }
+}
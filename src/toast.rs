@@ -0,0 +1,92 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::alert::Alert;
+use crate::flash::Flash;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ToastHorizontal {
+    Start,
+    Center,
+    #[default]
+    End,
+}
+
+impl Display for ToastHorizontal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToastHorizontal::Start => write!(f, "toast-start"),
+            ToastHorizontal::Center => write!(f, "toast-center"),
+            ToastHorizontal::End => write!(f, "toast-end"),
+        }
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ToastVertical {
+    Top,
+    Middle,
+    #[default]
+    Bottom,
+}
+
+impl Display for ToastVertical {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToastVertical::Top => write!(f, "toast-top"),
+            ToastVertical::Middle => write!(f, "toast-middle"),
+            ToastVertical::Bottom => write!(f, "toast-bottom"),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ToastProps {
+    pub children: Element,
+    #[props(default)]
+    pub horizontal: ToastHorizontal,
+    #[props(default)]
+    pub vertical: ToastVertical,
+    /// All standard HTML div attributes (id, style, etc.)
+    #[props(extends = div, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn Toast(props: ToastProps) -> Element {
+    let horizontal = props.horizontal.to_string();
+    let vertical = props.vertical.to_string();
+
+    rsx!(
+        div { class: "toast {horizontal} {vertical}", ..props.attributes, {props.children} }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FlashesProps {
+    pub flashes: Vec<Flash>,
+    #[props(default)]
+    pub horizontal: ToastHorizontal,
+    #[props(default)]
+    pub vertical: ToastVertical,
+}
+
+/// Shows each flash message as an `Alert` inside a `Toast`.
+#[component]
+pub fn Flashes(props: FlashesProps) -> Element {
+    if props.flashes.is_empty() {
+        return rsx!();
+    }
+
+    rsx!(
+        Toast { horizontal: props.horizontal, vertical: props.vertical,
+            for flash in props.flashes {
+                Alert { alert_color: flash.level, role: "alert",
+                    span { "{flash.message}" }
+                }
+            }
+        }
+    )
+}
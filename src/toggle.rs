@@ -0,0 +1,65 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::color::{Color, ColorPrefix};
+use crate::form::{FieldMessages, FieldState, sets};
+use crate::size::{Size, SizePrefix};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ToggleProps {
+    /// Label text displayed next to the toggle
+    pub label: String,
+    /// Name, value, validation errors and hint from the server
+    pub field: Option<FieldState>,
+    #[props(default)]
+    pub toggle_size: Size,
+    #[props(default)]
+    pub toggle_scheme: Color,
+    /// All standard HTML input attributes (name, value, checked, onchange, etc.)
+    #[props(extends = input, extends = GlobalAttributes)]
+    pub attributes: Vec<Attribute>,
+}
+
+impl ColorPrefix for ToggleProps {
+    const COLOR_PREFIX: &'static str = "toggle";
+}
+
+impl SizePrefix for ToggleProps {
+    const SIZE_PREFIX: &'static str = "toggle";
+}
+
+/// A checkbox styled as a switch, for on/off settings.
+#[component]
+pub fn Toggle(props: ToggleProps) -> Element {
+    let scheme = props.toggle_scheme.class::<ToggleProps>();
+    let size = props.toggle_size.class::<ToggleProps>();
+    let field_attributes = props
+        .field
+        .as_ref()
+        .map(|field| field.attributes_except(&props.attributes))
+        .unwrap_or_default();
+    let error = props
+        .field
+        .as_ref()
+        .map(FieldState::error_class::<ToggleProps>)
+        .unwrap_or_default();
+    let checked = props
+        .field
+        .as_ref()
+        .is_some_and(|field| field.is_checked() && !sets(&props.attributes, "checked"))
+        .then_some(true);
+
+    rsx!(
+        label { class: "flex items-center gap-2 cursor-pointer",
+            input {
+                r#type: "checkbox",
+                class: "toggle {scheme} {size} {error}",
+                checked,
+                ..field_attributes,
+                ..props.attributes,
+            }
+            span { "{props.label}" }
+            FieldMessages { field: props.field }
+        }
+    )
+}
@@ -1,44 +1,29 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
-pub enum ToolTipColor {
-    #[default]
-    Default,
-    Warn,
-    Info,
-    Error,
-    Success,
-}
+use crate::color::{Color, ColorPrefix};
 
-impl Display for ToolTipColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ToolTipColor::Default => write!(f, ""),
-            ToolTipColor::Info => write!(f, "tooltip-info"),
-            ToolTipColor::Warn => write!(f, "tooltip-warning"),
-            ToolTipColor::Error => write!(f, "tooltip-error"),
-            ToolTipColor::Success => write!(f, "tooltip-success"),
-        }
-    }
-}
+#[deprecated(since = "0.1.48", note = "use `Color` instead")]
+pub type ToolTipColor = Color;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ToolTipProps {
     pub text: String,
     pub children: Element,
     #[props(default)]
-    pub tooltip_color: ToolTipColor,
+    pub tooltip_color: Color,
     /// All standard HTML div attributes (id, style, onclick, etc.)
     #[props(extends = div, extends = GlobalAttributes)]
     pub attributes: Vec<Attribute>,
 }
 
+impl ColorPrefix for ToolTipProps {
+    const COLOR_PREFIX: &'static str = "tooltip";
+}
+
 #[component]
 pub fn ToolTip(props: ToolTipProps) -> Element {
-    let color = props.tooltip_color.to_string();
+    let color = props.tooltip_color.class::<ToolTipProps>();
 
     rsx!(
         div {
@@ -0,0 +1,141 @@
+#![cfg(feature = "axum")]
+
+use axum::body::Body;
+use axum::http::header::{CONTENT_TYPE, COOKIE, SET_COOKIE, VARY};
+use axum::http::{Request, StatusCode};
+use axum::response::{IntoResponse, Redirect, Response};
+use axum::routing::{get, post};
+use axum::{Extension, Router};
+use daisy_rsx::Flash;
+use daisy_rsx::app_layout::AppLayoutProps;
+use daisy_rsx::axum::{FlashMessages, Fragment, Layout, PageLayout, SetFlash};
+use dioxus::prelude::*;
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+fn app() -> Router {
+    let layout = PageLayout::new(|content| {
+        AppLayoutProps::builder()
+            .title(content.title)
+            .stylesheets(vec![])
+            .flashes(content.flashes)
+            .header(rsx! {})
+            .sidebar(rsx! {})
+            .sidebar_header(rsx! {})
+            .sidebar_footer(rsx! {})
+            .children(content.body)
+            .build()
+    });
+
+    Router::new()
+        .route("/", get(dashboard))
+        .route("/row", get(row))
+        .route("/save", post(save))
+        .route("/flashes", get(flashes))
+        .layer(Extension(layout))
+}
+
+async fn dashboard(layout: Layout) -> Response {
+    layout.page("Dashboard", rsx! { h1 { "Welcome" } })
+}
+
+async fn row() -> Fragment {
+    Fragment(rsx! { tr { td { "Row" } } })
+}
+
+async fn save() -> impl IntoResponse {
+    (SetFlash(vec![Flash::success("Saved")]), Redirect::to("/"))
+}
+
+async fn flashes(FlashMessages(flashes): FlashMessages) -> String {
+    format!("{}", flashes.len())
+}
+
+async fn send(request: Request<Body>) -> (Response<()>, String) {
+    let response = app().oneshot(request).await.unwrap();
+    let (parts, body) = response.into_parts();
+    let bytes = body.collect().await.unwrap().to_bytes();
+    (
+        Response::from_parts(parts, ()),
+        String::from_utf8(bytes.to_vec()).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn renders_pages_in_the_layout() {
+    let (response, body) = send(Request::get("/").body(Body::empty()).unwrap()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], "text/html; charset=utf-8");
+    assert!(body.starts_with("<!DOCTYPE html><html><head><title>Dashboard</title>"));
+    assert!(body.contains("<h1>Welcome</h1>"));
+    assert!(response.headers().get(SET_COOKIE).is_none());
+}
+
+#[tokio::test]
+async fn renders_only_the_content_for_htmx() {
+    let request = Request::get("/")
+        .header("HX-Request", "true")
+        .body(Body::empty())
+        .unwrap();
+    let (response, body) = send(request).await;
+    assert!(body.starts_with("<title>Dashboard</title><header"));
+    assert!(body.contains("<h1>Welcome</h1>"));
+    assert!(!body.contains("<html"));
+    assert!(!body.contains("id=\"sidebar\""));
+    assert_eq!(response.headers()[VARY], "HX-Request");
+
+    let request = Request::get("/")
+        .header("HX-Request", "true")
+        .header("HX-Boosted", "true")
+        .body(Body::empty())
+        .unwrap();
+    assert!(send(request).await.1.starts_with("<!DOCTYPE html>"));
+}
+
+#[tokio::test]
+async fn renders_fragments_on_their_own() {
+    let (_, body) = send(Request::get("/row").body(Body::empty()).unwrap()).await;
+    assert_eq!(body, "<tr><td>Row</td></tr>");
+}
+
+#[tokio::test]
+async fn sets_flash_cookie_on_redirect() {
+    let (response, _) = send(Request::post("/save").body(Body::empty()).unwrap()).await;
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    assert_eq!(
+        response.headers()[SET_COOKIE],
+        "flash=success=Saved; Path=/; HttpOnly; SameSite=Lax"
+    );
+}
+
+#[tokio::test]
+async fn shows_and_clears_flashes() {
+    let request = Request::get("/")
+        .header(COOKIE, "session=abc; flash=success=Saved")
+        .body(Body::empty())
+        .unwrap();
+    let (response, body) = send(request).await;
+    assert!(body.contains("Saved"));
+    assert!(
+        response.headers()[SET_COOKIE]
+            .to_str()
+            .unwrap()
+            .contains("Max-Age=0")
+    );
+
+    let request = Request::get("/flashes")
+        .header(COOKIE, "flash=info=One&error=Two")
+        .body(Body::empty())
+        .unwrap();
+    assert_eq!(send(request).await.1, "2");
+}
+
+#[tokio::test]
+async fn missing_layout_is_a_server_error() {
+    let app = Router::new().route("/", get(dashboard));
+    let response = app
+        .oneshot(Request::get("/").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
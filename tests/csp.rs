@@ -0,0 +1,26 @@
+use daisy_rsx::ssr::render_fragment;
+use daisy_rsx::{CspNonce, CspProvider, CssLength, RadialProgress};
+use dioxus::prelude::*;
+
+#[test]
+fn header_value_allows_the_nonce() {
+    let header = CspNonce::new("r4nd0m").header_value();
+    assert!(header.contains("script-src 'self' 'nonce-r4nd0m';"));
+    assert!(header.contains("style-src 'self' 'nonce-r4nd0m';"));
+    assert!(header.contains("object-src 'none'"));
+    assert!(!header.contains("unsafe-inline"));
+}
+
+#[test]
+fn radial_progress_has_no_inline_style_attribute() {
+    let html = render_fragment(rsx! {
+        CspProvider { nonce: CspNonce::new("r4nd0m"),
+            RadialProgress { value: 40, size: CssLength::Rem(1.5), thickness: CssLength::Px(3.0) }
+        }
+    });
+    assert!(!html.contains(" style="), "{html}");
+    assert!(html.contains(
+        "<style nonce=\"r4nd0m\">.radial-progress-40-s1_5rem-t3px{--value:40;--size:1.5rem;--thickness:3px;}</style>"
+    ), "{html}");
+    assert!(html.contains("class=\"radial-progress radial-progress-40-s1_5rem-t3px "));
+}
@@ -0,0 +1,19 @@
+#![allow(deprecated)]
+use daisy_rsx::*;
+
+#[test]
+fn old_color_enums_alias_color() {
+    let scheme: ButtonScheme = ButtonScheme::Primary;
+    assert_eq!(scheme, Color::Primary);
+    assert_eq!(AlertColor::Warn, Color::Warning);
+    assert_eq!(ToolTipColor::default(), Color::Default);
+    assert_eq!(BadgeColor::Error.class_with_prefix("badge"), "badge-error");
+}
+
+#[test]
+fn old_size_enums_alias_size() {
+    let size: BadgeSize = BadgeSize::Md;
+    assert_eq!(size, Size::Medium);
+    assert_eq!(LoadingSize::ExtraLarge, Size::ExtraLarge);
+    assert_eq!(ButtonSize::Small.class_with_prefix("btn"), "btn-sm");
+}
@@ -0,0 +1,23 @@
+use daisy_rsx::ssr::render_fragment;
+use daisy_rsx::{Drawer, DrawerBody};
+use dioxus::prelude::*;
+
+#[test]
+fn closes_with_buttons() {
+    let html = render_fragment(rsx! {
+        Drawer { trigger_id: "settings", label: "Settings",
+            DrawerBody { "Body" }
+        }
+    });
+    let close = html
+        .find("aria-label=\"Close\">✕")
+        .expect("a close control");
+    let control = &html[html[..close].rfind('<').unwrap()..close];
+    assert!(control.starts_with("<button type=\"button\""), "{control}");
+    assert!(control.contains("popovertarget=\"settings\""), "{control}");
+    assert!(
+        control.contains("popovertargetaction=\"hide\""),
+        "{control}"
+    );
+    assert!(!html.contains("<label"), "{html}");
+}
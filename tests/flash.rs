@@ -0,0 +1,35 @@
+use daisy_rsx::Color;
+use daisy_rsx::flash::{
+    Flash, clear_cookie_header, decode_flashes, encode_flashes, from_cookie_header,
+    set_cookie_header,
+};
+
+#[test]
+fn round_trips_through_a_cookie_value() {
+    let flashes = vec![
+        Flash::success("Saved, 100% done; see you"),
+        Flash::new(Color::Default, "plain"),
+        Flash::error("ünïcode = fine & dandy"),
+    ];
+    let value = encode_flashes(&flashes);
+    assert!(!value.contains([' ', ',', ';', '"', '\\']));
+    assert_eq!(decode_flashes(&value), flashes);
+}
+
+#[test]
+fn skips_unknown_levels() {
+    assert_eq!(
+        decode_flashes("loud=Hey&info=Hi&garbage"),
+        vec![Flash::info("Hi")]
+    );
+}
+
+#[test]
+fn reads_the_cookie_header() {
+    let set = set_cookie_header(&[Flash::warning("Careful")]);
+    let value = set.split(';').next().unwrap();
+    let header = format!("session=abc; {value}; other=1");
+    assert_eq!(from_cookie_header(&header), vec![Flash::warning("Careful")]);
+    assert!(from_cookie_header("session=abc").is_empty());
+    assert!(clear_cookie_header().contains("Max-Age=0"));
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use daisy_rsx::ssr::render_fragment;
+use daisy_rsx::{DaisyForm, FieldState, FormErrors, Input, Select, SelectOption, TextArea};
+use dioxus::prelude::*;
+
+#[test]
+fn builds_field_states_from_errors() {
+    let errors: FormErrors = [
+        ("email", "Is invalid"),
+        ("email", "Is taken"),
+        ("name", "Is blank"),
+    ]
+    .into_iter()
+    .collect();
+
+    let email = errors.field("email", "daisy@");
+    assert_eq!(email.value.as_deref(), Some("daisy@"));
+    assert_eq!(email.errors, vec!["Is invalid", "Is taken"]);
+    assert!(email.is_invalid());
+
+    let age = errors.field("age", "7");
+    assert!(!age.is_invalid());
+    assert_eq!(age.described_by(), None);
+}
+
+#[test]
+fn converts_validation_maps() {
+    let map = HashMap::from([
+        ("title".to_string(), vec!["Is blank".to_string()]),
+        ("body".to_string(), vec![]),
+    ]);
+    let errors = FormErrors::from(map);
+    assert_eq!(errors.get("title"), ["Is blank"]);
+    assert!(errors.get("body").is_empty());
+    assert_eq!(errors.iter().count(), 1);
+}
+
+#[test]
+fn ids_are_sanitised_for_aria() {
+    let field = FieldState::new("user[email]")
+        .hint("We never share it")
+        .error("Is blank");
+    assert_eq!(field.id(), "user-email-");
+    assert_eq!(
+        field.described_by().as_deref(),
+        Some("user-email--hint user-email--error")
+    );
+}
+
+#[derive(DaisyForm)]
+enum Size {
+    #[allow(dead_code)]
+    Small,
+    Large,
+}
+
+#[derive(DaisyForm)]
+struct Order {
+    gift: Option<bool>,
+    size: Option<Size>,
+    quantity: Option<i32>,
+}
+
+#[test]
+fn empty_options_render_the_same_control() {
+    let render = |order: Order| render_fragment(order.fieldset(&FormErrors::new()));
+    let empty = render(Order {
+        gift: None,
+        size: None,
+        quantity: None,
+    });
+    let filled = render(Order {
+        gift: Some(true),
+        size: Some(Size::Large),
+        quantity: Some(2),
+    });
+    for html in [&empty, &filled] {
+        assert!(html.contains("type=\"checkbox\""), "{html}");
+        assert!(html.contains("<select"), "{html}");
+        assert!(html.contains("type=\"number\""), "{html}");
+    }
+    assert!(!empty.contains("checked"), "{empty}");
+    assert!(!empty.contains("selected"), "{empty}");
+}
+
+#[test]
+fn explicit_attributes_override_the_field() {
+    let field = || FieldState::new("user[name]").value("Daisy").required();
+    let html = render_fragment(rsx! {
+        Input { label: "Name", field: field(), name: "name", value: "Ada" }
+        Select { label: "Name", field: field(), name: "name", value: "ada",
+            SelectOption { value: "daisy", "Daisy" }
+            SelectOption { value: "ada", "Ada" }
+        }
+        TextArea { label: "Name", field: field(), name: "name", value: "Ada" }
+    });
+    assert_eq!(html.matches("name=\"name\"").count(), 3, "{html}");
+    assert!(!html.contains("user[name]"), "{html}");
+    assert!(
+        !html.contains("Daisy\"") && !html.contains(">Daisy</textarea>"),
+        "{html}"
+    );
+    assert!(
+        html.contains("<option selected=true value=\"ada\">"),
+        "{html}"
+    );
+    assert_eq!(html.matches("required").count(), 3, "{html}");
+}
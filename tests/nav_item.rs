@@ -0,0 +1,31 @@
+use daisy_rsx::ssr::render_fragment;
+use daisy_rsx::{NavSubGroup, NavSubItem};
+use dioxus::prelude::*;
+
+#[test]
+fn groups_open_around_the_selected_item_whatever_its_id() {
+    let html = render_fragment(rsx! {
+        NavSubGroup { title: "Settings", selected_item_id: "billing",
+            NavSubItem { id: "profile", href: "/settings/profile", title: "Profile" }
+            NavSubGroup { title: "Payments",
+                NavSubItem { id: "billing", href: "/settings/billing", title: "Billing" }
+            }
+        }
+        NavSubGroup { title: "Reports", selected_item_id: "billing",
+            NavSubItem { id: "usage", href: "/reports/usage", title: "Usage" }
+        }
+    });
+    assert!(
+        html.contains("<details open=true><summary>Settings"),
+        "{html}"
+    );
+    assert!(
+        html.contains("<details open=true><summary>Payments"),
+        "{html}"
+    );
+    assert!(html.contains("<details><summary>Reports"), "{html}");
+    assert!(
+        html.contains("href=\"/settings/billing\" aria-current=\"page\""),
+        "{html}"
+    );
+}
@@ -0,0 +1,74 @@
+use daisy_rsx::ssr::render_fragment;
+use daisy_rsx::{NavTree, NavTreeGroup, NavTreeItem, SidebarNav};
+use dioxus::prelude::*;
+
+fn tree() -> NavTree {
+    NavTree::new()
+        .group(
+            NavTreeGroup::new("Workspace")
+                .item(NavTreeItem::new("Dashboard", "/"))
+                .item(NavTreeItem::new("Projects", "/projects"))
+                .item(
+                    NavTreeItem::new("Settings", "/settings")
+                        .child(NavTreeItem::new("Profile", "/settings/profile"))
+                        .child(
+                            NavTreeItem::new("Billing", "/settings/billing").permission("owner"),
+                        ),
+                ),
+        )
+        .group(
+            NavTreeGroup::new("Admin")
+                .item(NavTreeItem::new("Users", "/admin/users").permission("admin")),
+        )
+}
+
+#[test]
+fn selects_the_closest_item() {
+    let tree = tree();
+    assert_eq!(tree.selected_href("/"), Some("/"));
+    assert_eq!(tree.selected_href("/projects"), Some("/projects"));
+    assert_eq!(tree.selected_href("/projects/42/edit"), Some("/projects"));
+    assert_eq!(tree.selected_href("/projects?page=2"), Some("/projects"));
+    assert_eq!(
+        tree.selected_href("/settings/profile"),
+        Some("/settings/profile")
+    );
+    assert_eq!(tree.selected_href("/projectsx"), None);
+    assert_eq!(tree.selected_href("/unlisted/page"), None);
+    assert_eq!(NavTree::new().selected_href("/"), None);
+}
+
+#[test]
+fn hides_items_without_permission() {
+    let visible = tree().visible(|permission| permission == "owner");
+    assert_eq!(visible.groups.len(), 1);
+    assert_eq!(visible.groups[0].items[2].children.len(), 2);
+
+    let visible = tree().visible(|_| false);
+    let settings = &visible.groups[0].items[2];
+    assert_eq!(settings.children.len(), 1);
+    assert_eq!(visible.selected_href("/admin/users"), None);
+
+    let only_owner = NavTree::new().group(
+        NavTreeGroup::new("Settings").item(
+            NavTreeItem::new("Settings", "/settings")
+                .child(NavTreeItem::new("Billing", "/settings/billing").permission("owner")),
+        ),
+    );
+    assert!(only_owner.visible(|_| false).groups.is_empty());
+}
+
+#[test]
+fn selected_parent_shows_its_own_link() {
+    let html = render_fragment(rsx! {
+        SidebarNav { tree: tree(), current_path: "/settings" }
+    });
+    assert!(
+        html.contains("<details open=true><summary>Settings"),
+        "{html}"
+    );
+    assert!(
+        html.contains("<li class=\"active\"><a href=\"/settings\" aria-current=\"page\" data-turbo-frame=\"main-content\">Settings</a>"),
+        "{html}"
+    );
+}
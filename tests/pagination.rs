@@ -0,0 +1,59 @@
+use daisy_rsx::{PageItem, PageUrl, page_window};
+
+use PageItem::{Ellipsis, Page};
+
+#[test]
+fn window_in_the_middle() {
+    assert_eq!(
+        page_window(5, 20, 1),
+        vec![
+            Page(1),
+            Ellipsis,
+            Page(4),
+            Page(5),
+            Page(6),
+            Ellipsis,
+            Page(20)
+        ]
+    );
+}
+
+#[test]
+fn window_at_the_edges() {
+    assert_eq!(
+        page_window(1, 20, 1),
+        vec![Page(1), Page(2), Ellipsis, Page(20)]
+    );
+    assert_eq!(
+        page_window(20, 20, 1),
+        vec![Page(1), Ellipsis, Page(19), Page(20)]
+    );
+}
+
+#[test]
+fn single_page_gaps_are_not_elided() {
+    assert_eq!(
+        page_window(4, 7, 1),
+        vec![
+            Page(1),
+            Page(2),
+            Page(3),
+            Page(4),
+            Page(5),
+            Page(6),
+            Page(7)
+        ]
+    );
+}
+
+#[test]
+fn out_of_range_pages_are_clamped() {
+    assert_eq!(page_window(0, 0, 1), vec![Page(1)]);
+    assert_eq!(page_window(9, 3, 1), vec![Page(1), Page(2), Page(3)]);
+}
+
+#[test]
+fn query_urls_keep_other_params() {
+    let url = PageUrl::query("/users?q=a%20b&page=2#list");
+    assert_eq!(url.href(3), "/users?q=a%20b&page=3#list");
+}
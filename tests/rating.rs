@@ -0,0 +1,28 @@
+use daisy_rsx::Rating;
+use daisy_rsx::ssr::render_fragment;
+use dioxus::prelude::*;
+
+fn checked_value(html: &str) -> &str {
+    let checked = html.find("checked=true").expect("a checked radio");
+    let input = &html[html[..checked].rfind("<input").unwrap()..];
+    let value = &input[input.find("value=\"").unwrap() + 7..];
+    &value[..value.find('"').unwrap()]
+}
+
+#[test]
+fn clamps_out_of_range_values() {
+    let html = render_fragment(rsx! {
+        Rating { name: "score", value: 9.0, count: 3 }
+    });
+    assert_eq!(checked_value(&html), "3");
+
+    let html = render_fragment(rsx! {
+        Rating { name: "score", value: -2.0, count: 3 }
+    });
+    assert_eq!(checked_value(&html), "0");
+
+    let html = render_fragment(rsx! {
+        Rating { name: "score", value: 7.0, half: true, read_only: true, count: 5 }
+    });
+    assert!(html.contains("aria-label=\"5 out of 5\""), "{html}");
+}
@@ -0,0 +1,1674 @@
+#![allow(non_snake_case)]
+use std::fmt::Debug;
+
+use daisy_rsx::app_layout::AppLayoutProps;
+use daisy_rsx::snapshot::{assert_html_snapshot, assert_snapshot};
+use daisy_rsx::ssr::{render_content, render_fragment, render_page};
+use daisy_rsx::*;
+use dioxus::prelude::*;
+
+const SNAPSHOTS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots");
+
+/// Render one labelled fragment per variant so a snapshot covers a whole enum.
+fn variants<T: Copy + Debug>(items: &[T], render: impl Fn(T) -> Element) -> String {
+    items
+        .iter()
+        .map(|item| format!("<!-- {item:?} -->\n{}\n", render_fragment(render(*item))))
+        .collect()
+}
+
+#[test]
+fn accordian() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "accordian",
+        rsx! {
+            Accordian { name: "faq", title: "Question", checked: true, "Answer" }
+        },
+    );
+}
+
+#[test]
+fn alert() {
+    let html = variants(&Color::ALL, |color| {
+        rsx! {
+            Alert { alert_color: color, "Message" }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "alert", &html);
+}
+
+#[test]
+fn app_layout() {
+    let html = render_page(
+        AppLayoutProps::builder()
+            .title("Dashboard".to_string())
+            .fav_icon_src(Some("/favicon.svg".to_string()))
+            .stylesheets(vec!["/tailwind.css".to_string()])
+            .js_href(Some("/index.js".to_string()))
+            .theme(Some(Theme::Corporate))
+            .flashes(vec![Flash::success("Saved")])
+            .header(rsx! {
+                h1 { "Dashboard" }
+            })
+            .sidebar(rsx! {
+                p { "Sidebar" }
+            })
+            .sidebar_header(rsx! {
+                p { "Logo" }
+            })
+            .sidebar_footer(rsx! {
+                p { "Footer" }
+            })
+            .children(rsx! {
+                p { "Content" }
+            })
+            .build(),
+    );
+    assert_html_snapshot(SNAPSHOTS, "app_layout", &html);
+}
+
+#[test]
+fn app_layout_content() {
+    let html = render_content(
+        AppLayoutProps::builder()
+            .title("Projects".to_string())
+            .stylesheets(vec!["/tailwind.css".to_string()])
+            .flashes(vec![Flash::info("Loaded")])
+            .header(rsx! {
+                h1 { "Projects" }
+            })
+            .sidebar(rsx! {
+                p { "Sidebar" }
+            })
+            .sidebar_header(rsx! {})
+            .sidebar_footer(rsx! {})
+            .children(rsx! {
+                p { "Content" }
+            })
+            .build(),
+    );
+    assert_html_snapshot(SNAPSHOTS, "app_layout_content", &html);
+}
+
+#[test]
+fn app_layout_collapsible_sidebar() {
+    let html = render_page(
+        AppLayoutProps::builder()
+            .title("Dashboard".to_string())
+            .stylesheets(vec![])
+            .sidebar_width(SidebarWidth::Large)
+            .sidebar_collapsible(true)
+            .sidebar_right(true)
+            .header(rsx! {})
+            .sidebar(rsx! {
+                p { "Sidebar" }
+            })
+            .sidebar_header(rsx! {})
+            .sidebar_footer(rsx! {})
+            .children(rsx! {})
+            .build(),
+    );
+    assert_html_snapshot(SNAPSHOTS, "app_layout_collapsible_sidebar", &html);
+}
+
+#[test]
+fn app_layout_page_meta() {
+    let meta = PageMeta::new()
+        .lang("en")
+        .description("Pricing for teams of every size")
+        .canonical("https://example.com/pricing")
+        .robots("noindex")
+        .theme_color("#570df8")
+        .open_graph(
+            OpenGraph::new("Pricing")
+                .description("Plans & prices")
+                .image("https://example.com/og.png")
+                .url("https://example.com/pricing")
+                .site_name("Example"),
+        )
+        .twitter_card("summary_large_image")
+        .twitter_site("@example")
+        .preconnect("https://fonts.gstatic.com")
+        .preload(Preload::font("/inter.woff2", "font/woff2"))
+        .script(Script::new("/analytics.js").defer().nonce("abc123"))
+        .script(Script::new("/widget.js").r#async())
+        .script(Script::new("/app.js").module())
+        .extra(rsx! {
+            link { rel: "manifest", href: "/site.webmanifest" }
+        });
+    let html = render_page(
+        AppLayoutProps::builder()
+            .title("Pricing".to_string())
+            .stylesheets(vec![])
+            .meta(meta)
+            .header(rsx! {})
+            .sidebar(rsx! {})
+            .sidebar_header(rsx! {})
+            .sidebar_footer(rsx! {})
+            .children(rsx! {})
+            .build(),
+    );
+    assert_html_snapshot(SNAPSHOTS, "app_layout_page_meta", &html);
+}
+
+#[test]
+fn app_layout_csp_nonce() {
+    let html = render_page(
+        AppLayoutProps::builder()
+            .title("Dashboard".to_string())
+            .stylesheets(vec!["/tailwind.css".to_string()])
+            .js_href(Some("/index.js".to_string()))
+            .csp_nonce(Some(CspNonce::new("r4nd0m")))
+            .meta(
+                PageMeta::new()
+                    .script(Script::new("/analytics.js").defer())
+                    .script(Script::new("/vendor.js").nonce("own")),
+            )
+            .header(rsx! {})
+            .sidebar(rsx! {})
+            .sidebar_header(rsx! {})
+            .sidebar_footer(rsx! {})
+            .children(rsx! {
+                InlineScript { code: "console.log('hi')" }
+            })
+            .build(),
+    );
+    assert_html_snapshot(SNAPSHOTS, "app_layout_csp_nonce", &html);
+}
+
+#[test]
+fn csp_provider() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "csp_provider",
+        rsx! {
+            CspProvider { nonce: CspNonce::new("r4nd0m"),
+                InlineStyle { css: ".hidden {{ display: none }}" }
+                InlineScript { code: "import './app.js'", module: true }
+            }
+            InlineScript { code: "console.log('no nonce')" }
+        },
+    );
+}
+
+#[test]
+fn avatar() {
+    let sizes = [
+        AvatarSize::Small,
+        AvatarSize::Medium,
+        AvatarSize::Large,
+        AvatarSize::ExtraLarge,
+    ];
+    let mut html = variants(&sizes, |avatar_size| {
+        rsx! {
+            Avatar { avatar_size, image_src: "/me.png" }
+        }
+    });
+    html += &variants(&[AvatarType::User, AvatarType::Team], |avatar_type| {
+        rsx! {
+            Avatar { avatar_type, name: "Daisy" }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "avatar", &html);
+}
+
+#[test]
+fn badge() {
+    let mut html = variants(&Color::ALL, |badge_color| {
+        rsx! {
+            Badge { badge_color, "Badge" }
+        }
+    });
+    html += &variants(&Size::ALL, |badge_size| {
+        rsx! {
+            Badge { badge_size, "Badge" }
+        }
+    });
+    let styles = [
+        BadgeStyle::None,
+        BadgeStyle::Outline,
+        BadgeStyle::Dash,
+        BadgeStyle::Soft,
+        BadgeStyle::Ghost,
+    ];
+    html += &variants(&styles, |badge_style| {
+        rsx! {
+            Badge { badge_style, "Badge" }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "badge", &html);
+}
+
+#[test]
+fn blank_slate() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "blank_slate",
+        rsx! {
+            BlankSlate {
+                heading: "No documents",
+                visual: "/empty.svg",
+                description: "Upload a document to get started",
+                primary_action: ("Upload".to_string(), "/upload".to_string()),
+                primary_action_drawer: ("Create".to_string(), "create-drawer".to_string()),
+                secondary_action: ("Learn more".to_string(), "/docs".to_string()),
+            }
+        },
+    );
+}
+
+#[test]
+fn breadcrumb() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "breadcrumb",
+        rsx! {
+            Breadcrumb {
+                items: vec![
+                    BreadcrumbItem {
+                        text: "Home".to_string(),
+                        href: Some("/".to_string()),
+                    },
+                    BreadcrumbItem {
+                        text: "Settings".to_string(),
+                        href: None,
+                    },
+                ],
+            }
+        },
+    );
+}
+
+#[test]
+fn button() {
+    let mut html = variants(&Color::ALL, |button_scheme| {
+        rsx! {
+            Button { button_scheme, "Button" }
+        }
+    });
+    html += &variants(&Size::ALL, |button_size| {
+        rsx! {
+            Button { button_size, "Button" }
+        }
+    });
+    let shapes = [
+        ButtonShape::Default,
+        ButtonShape::Circle,
+        ButtonShape::Square,
+    ];
+    html += &variants(&shapes, |button_shape| {
+        rsx! {
+            Button { button_shape, "B" }
+        }
+    });
+    let styles = [
+        ButtonStyle::Default,
+        ButtonStyle::Outline,
+        ButtonStyle::Dash,
+        ButtonStyle::Soft,
+        ButtonStyle::Ghost,
+        ButtonStyle::Link,
+    ];
+    html += &variants(&styles, |button_style| {
+        rsx! {
+            Button { button_style, "Button" }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "button", &html);
+}
+
+#[test]
+fn card() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "card",
+        rsx! {
+            Card {
+                CardHeader { title: "Title", "Actions" }
+                CardBody { "Body" }
+            }
+        },
+    );
+}
+
+#[test]
+fn check_box() {
+    let mut html = variants(&Color::ALL, |checkbox_scheme| {
+        rsx! {
+            CheckBox { label: "Remember me", checkbox_scheme }
+        }
+    });
+    html += &variants(&Size::ALL, |checkbox_size| {
+        rsx! {
+            CheckBox { label: "Remember me", checkbox_size }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "check_box", &html);
+}
+
+#[test]
+fn drawer() {
+    let mut html = variants(&[None, Some("/save")], |submit_action| {
+        rsx! {
+            Drawer {
+                trigger_id: "settings",
+                label: "Settings",
+                submit_action: submit_action.map(str::to_string),
+                DrawerBody { "Body" }
+                DrawerFooter { "Footer" }
+            }
+        }
+    });
+    html += &variants(&[DrawerSide::Left, DrawerSide::Right], |side| {
+        rsx! {
+            Drawer { trigger_id: "filters", label: "Filters", side, width: DrawerWidth::Large,
+                DrawerBody { "Body" }
+            }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "drawer", &html);
+}
+
+#[test]
+fn drop_down() {
+    let directions = [
+        Direction::None,
+        Direction::Top,
+        Direction::Bottom,
+        Direction::Left,
+        Direction::Right,
+    ];
+    let html = variants(&directions, |direction| {
+        rsx! {
+            DropDown { button_text: "Menu", direction, carat: true,
+                DropDownLink { href: "/edit", "Edit" }
+            }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "drop_down", &html);
+}
+
+#[test]
+fn fieldset() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "fieldset",
+        rsx! {
+            Fieldset { legend: "Profile",
+                Input { label: "Name", name: "name" }
+            }
+        },
+    );
+}
+
+#[test]
+fn file_input() {
+    let mut html = variants(&Color::ALL, |file_input_color| {
+        rsx! {
+            FileInput { label: "Upload", file_input_color }
+        }
+    });
+    html += &variants(&Size::ALL, |file_input_size| {
+        rsx! {
+            FileInput { label: "Upload", file_input_size }
+        }
+    });
+    html += &variants(
+        &[FileInputStyle::Default, FileInputStyle::Ghost],
+        |file_input_style| {
+            rsx! {
+                FileInput { label: "Upload", file_input_style }
+            }
+        },
+    );
+    assert_html_snapshot(SNAPSHOTS, "file_input", &html);
+}
+
+fn invalid_field(name: &str) -> FieldState {
+    FieldState::new(name)
+        .value("42")
+        .hint("Shown to other members")
+        .required()
+        .error("Is too short")
+        .error("Must be unique")
+}
+
+#[test]
+fn form_fields() {
+    let html = [
+        render_fragment(rsx! {
+            Input { label: "Name", field: invalid_field("user[name]") }
+        }),
+        render_fragment(rsx! {
+            Input { label: "Name", field: FieldState::new("name").value("Daisy").hint("Your full name") }
+        }),
+        render_fragment(rsx! {
+            Select { label: "Role", field: invalid_field("role"),
+                SelectOption { value: "admin", "Admin" }
+            }
+        }),
+        render_fragment(rsx! {
+            TextArea { label: "Bio", field: invalid_field("bio") }
+        }),
+        render_fragment(rsx! {
+            CheckBox { label: "Accept", field: FieldState::new("accept").value("on").error("Required") }
+        }),
+        render_fragment(rsx! {
+            Range { label: "Volume", field: invalid_field("volume") }
+        }),
+        render_fragment(rsx! {
+            FileInput { label: "Avatar", field: invalid_field("avatar") }
+        }),
+    ]
+    .join("\n");
+    assert_html_snapshot(SNAPSHOTS, "form_fields", &html);
+}
+
+#[derive(DaisyForm)]
+enum Plan {
+    #[allow(dead_code)]
+    Free,
+    #[daisy(label = "Pro (monthly)", value = "pro")]
+    ProMonthly,
+}
+
+#[derive(DaisyForm)]
+#[daisy(legend = "Profile")]
+struct Profile {
+    #[daisy(hint = "Shown to other members", required)]
+    display_name: String,
+    #[daisy(widget = "email", name = "user[email]")]
+    email: String,
+    #[daisy(widget = "textarea")]
+    bio: Option<String>,
+    newsletter: bool,
+    plan: Plan,
+    age: u32,
+    #[daisy(widget = "range", min = 0, max = 10, step = 0.5)]
+    volume: f64,
+    #[daisy(skip)]
+    #[allow(dead_code)]
+    id: i64,
+}
+
+#[test]
+fn daisy_form() {
+    let profile = Profile {
+        display_name: "Daisy".into(),
+        email: "daisy@".into(),
+        bio: None,
+        newsletter: true,
+        plan: Plan::ProMonthly,
+        age: 7,
+        volume: 2.5,
+        id: 1,
+    };
+    let errors: FormErrors = [("user[email]", "Is invalid")].into_iter().collect();
+    assert_snapshot(SNAPSHOTS, "daisy_form", profile.fieldset(&errors));
+}
+
+#[test]
+fn input() {
+    let html = variants(&Size::ALL, |input_size| {
+        rsx! {
+            Input { label: "Email", input_size, name: "email" }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "input", &html);
+}
+
+#[test]
+fn loading() {
+    let mut html = variants(&Color::ALL, |loading_color| {
+        rsx! {
+            Loading { loading_color }
+        }
+    });
+    html += &variants(&Size::ALL, |loading_size| {
+        rsx! {
+            Loading { loading_size }
+        }
+    });
+    let types = [
+        LoadingType::Spinner,
+        LoadingType::Dots,
+        LoadingType::Ring,
+        LoadingType::Ball,
+        LoadingType::Bars,
+        LoadingType::Infinity,
+    ];
+    html += &variants(&types, |loading_type| {
+        rsx! {
+            Loading { loading_type }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "loading", &html);
+}
+
+#[test]
+fn modal() {
+    let mut html = variants(&[None, Some("/delete")], |submit_action| {
+        rsx! {
+            Modal {
+                trigger_id: "confirm",
+                submit_action: submit_action.map(str::to_string),
+                ModalBody {
+                    "Are you sure?"
+                    ModalAction { "Actions" }
+                }
+            }
+        }
+    });
+    html += &variants(
+        &[
+            ModalPlacement::Middle,
+            ModalPlacement::Top,
+            ModalPlacement::Bottom,
+            ModalPlacement::Responsive,
+        ],
+        |placement| {
+            rsx! {
+                Modal {
+                    trigger_id: "invite",
+                    title: "Invite a member",
+                    placement,
+                    close_button: false,
+                    backdrop: false,
+                    ModalBody { "Body" }
+                    ModalAction {
+                        ModalClose { trigger_id: "invite", "Cancel" }
+                    }
+                }
+            }
+        },
+    );
+    assert_html_snapshot(SNAPSHOTS, "modal", &html);
+}
+
+#[test]
+fn confirm_modal() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "confirm_modal",
+        rsx! {
+            ConfirmModal {
+                trigger_id: "delete-team",
+                title: "Delete team?",
+                message: "This removes every project in the team.",
+                submit_action: "/teams/delete",
+                hidden_fields: vec![("id".to_string(), "42".to_string())],
+                confirm_label: "Delete",
+            }
+        },
+    );
+}
+
+#[test]
+fn nav_item() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "nav_item",
+        rsx! {
+            NavGroup {
+                heading: "Main",
+                content: rsx! {
+                    NavItem {
+                        id: "home",
+                        selected_item_id: "home",
+                        href: "/",
+                        icon: "/home.svg",
+                        title: "Home",
+                    }
+                    NavItem {
+                        id: "settings",
+                        selected_item_id: "home",
+                        href: "/settings",
+                        icon: "/settings.svg",
+                        title: "Settings",
+                    }
+                    NavSubGroup {
+                        NavSubItem {
+                            id: "profile",
+                            selected_item_id: "profile",
+                            href: "/settings/profile",
+                            title: "Profile",
+                        }
+                    }
+                },
+            }
+        },
+    );
+}
+
+#[test]
+fn nav_sub_group() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "nav_sub_group",
+        rsx! {
+            ul { class: "menu",
+                NavSubGroup {
+                    title: "Settings",
+                    icon: NavIcon::from("/settings.svg"),
+                    badge: 2,
+                    selected_item_id: "settings/billing",
+                    NavSubItem { id: "settings/profile", href: "/settings/profile", title: "Profile" }
+                    NavSubItem { id: "settings/billing", href: "/settings/billing", title: "Billing" }
+                }
+                NavSubGroup {
+                    title: "Reports",
+                    selected_item_id: "settings/billing",
+                    NavSubItem { id: "reports/usage", href: "/reports/usage", title: "Usage" }
+                }
+                NavSubGroup { title: "Help", collapsible: false,
+                    NavSubItem { href: "/docs", title: "Docs" }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn nav_item_navigation() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "nav_item_navigation",
+        rsx! {
+            NavItem {
+                href: "/",
+                icon: rsx! {
+                    svg { class: "size-4" }
+                },
+                title: "Home",
+                navigation: Navigation::Link,
+            }
+            NavItem {
+                href: "/inbox",
+                icon: "/inbox.svg",
+                title: "Inbox",
+                badge: 3,
+                navigation: Navigation::TurboFrame("content".to_string()),
+            }
+            NavItem {
+                href: "/reports",
+                icon: "/reports.svg",
+                title: "Reports",
+                navigation: Navigation::HxBoost,
+            }
+            NavItem {
+                href: "/help",
+                icon: "/help.svg",
+                title: "Help",
+                navigation: Navigation::Attributes(
+                    vec![Attribute::new("target", "_blank", None, false)],
+                ),
+            }
+            NavSubItem {
+                id: "team",
+                selected_item_id: "team",
+                href: "/settings/team",
+                title: "Team",
+                icon: NavIcon::from("/team.svg"),
+                badge: 12,
+                navigation: Navigation::Link,
+            }
+        },
+    );
+}
+
+#[test]
+fn sidebar_nav() {
+    let tree = NavTree::new()
+        .group(
+            NavTreeGroup::new("Workspace")
+                .item(NavTreeItem::new("Dashboard", "/").icon("/home.svg"))
+                .item(
+                    NavTreeItem::new("Projects", "/projects")
+                        .icon("/projects.svg")
+                        .child(NavTreeItem::new("Active", "/projects/active").badge(4))
+                        .child(NavTreeItem::new("Archived", "/projects/archived")),
+                )
+                .item(
+                    NavTreeItem::new("Reports", "/reports")
+                        .child(NavTreeItem::new("Usage", "/reports/usage")),
+                ),
+        )
+        .group(
+            NavTreeGroup::new("Admin")
+                .item(NavTreeItem::new("Users", "/admin/users").permission("admin"))
+                .item(
+                    NavTreeItem::new("Help", "https://example.com/help")
+                        .navigation(Navigation::Link),
+                ),
+        );
+
+    assert_snapshot(
+        SNAPSHOTS,
+        "sidebar_nav",
+        rsx! {
+            SidebarNav {
+                tree,
+                current_path: "/projects/active/42",
+                permissions: NavPermissions::new(|permission| permission != "admin"),
+            }
+        },
+    );
+}
+
+#[test]
+fn pagination() {
+    let pages = [(None, None), (Some("/?page=1"), Some("/?page=3"))];
+    let html = variants(&pages, |(prev_page_url, next_page_url)| {
+        rsx! {
+            Pagination {
+                prev_page_url: prev_page_url.map(str::to_string),
+                next_page_url: next_page_url.map(str::to_string),
+            }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "pagination", &html);
+}
+
+#[test]
+fn numbered_pagination() {
+    let html = variants(&[1, 2, 5, 19, 20], |current_page| {
+        rsx! {
+            NumberedPagination {
+                current_page,
+                total_pages: 20,
+                page_url: PageUrl::query("/users?q=d&page=1"),
+            }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "numbered_pagination", &html);
+}
+
+#[test]
+fn numbered_pagination_from_items() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "numbered_pagination_from_items",
+        rsx! {
+            NumberedPagination {
+                current_page: 2,
+                total_items: 45,
+                page_size: 20,
+                page_url: PageUrl::Template("/posts/page/{page}".to_string()),
+                first_last: false,
+                pagination_size: Size::Small,
+            }
+        },
+    );
+}
+
+#[test]
+fn cursor_pagination() {
+    let cursors = [
+        (None, Some("c2")),
+        (Some("c1"), Some("c2")),
+        (Some("c1"), None),
+    ];
+    let html = variants(&cursors, |(before, after)| {
+        rsx! {
+            CursorPagination {
+                url: "/events?type=push&after=c0",
+                before: before.map(str::to_string),
+                after: after.map(str::to_string),
+            }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "cursor_pagination", &html);
+}
+
+#[test]
+fn progress() {
+    let mut html = variants(&Color::ALL, |color| {
+        rsx! {
+            Progress { value: 40, progress_color: color }
+        }
+    });
+    html += &render_fragment(rsx! {
+        Progress { "aria-label": "Loading" }
+        Progress {
+            value: 0,
+            max: 10,
+            sse: Sse::new("/exports/42/progress", "progress").close("done"),
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "progress", &html);
+}
+
+#[test]
+fn radial_progress() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "radial_progress",
+        rsx! {
+            RadialProgress { value: 70 }
+            RadialProgress {
+                value: 120,
+                size: CssLength::Rem(8.0),
+                thickness: CssLength::Px(4.0),
+                label: "Done",
+                radial_color: Color::Success,
+            }
+            RadialProgress { value: 5, sse: Sse::new("/jobs/7/progress", "progress") }
+        },
+    );
+}
+
+#[test]
+fn radial_progress_csp_nonce() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "radial_progress_csp_nonce",
+        rsx! {
+            CspProvider { nonce: CspNonce::new("r4nd0m"),
+                RadialProgress { value: 40, size: CssLength::Rem(1.5) }
+            }
+        },
+    );
+}
+
+#[test]
+fn radio() {
+    let mut html = variants(&Color::ALL, |radio_scheme| {
+        rsx! {
+            Radio { label: "Monthly", name: "billing", value: "monthly", radio_scheme }
+        }
+    });
+    html += &variants(&Size::ALL, |radio_size| {
+        rsx! {
+            Radio { label: "Monthly", name: "billing", value: "monthly", radio_size }
+        }
+    });
+    html += "\n";
+    html += &render_fragment(rsx! {
+        Radio {
+            label: "Monthly",
+            field: FieldState::new("billing").value("yearly").error("Choose a plan"),
+            value: "monthly",
+        }
+        Radio {
+            label: "Yearly",
+            field: FieldState::new("billing").value("yearly").error("Choose a plan"),
+            value: "yearly",
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "radio", &html);
+}
+
+#[test]
+fn radio_group() {
+    let options = vec![
+        (
+            Color::Primary,
+            "Primary".to_string(),
+            "Brand color".to_string(),
+        ),
+        (Color::Error, "Error".to_string(), String::new()),
+    ];
+    let html = [
+        render_fragment(rsx! {
+            RadioGroup {
+                legend: "Accent",
+                field: FieldState::new("theme[accent]").value("error").hint("Used for buttons"),
+                options: options.clone(),
+                radio_scheme: Color::Primary,
+            }
+        }),
+        render_fragment(rsx! {
+            RadioGroup {
+                legend: "Accent",
+                field: FieldState::new("accent").required().error("Choose a color"),
+                options,
+                radio_size: Size::Small,
+            }
+        }),
+    ]
+    .join("\n");
+    assert_html_snapshot(SNAPSHOTS, "radio_group", &html);
+}
+
+#[test]
+fn range() {
+    let mut html = variants(&Color::ALL, |range_color| {
+        rsx! {
+            Range { label: "Volume", range_color }
+        }
+    });
+    html += &variants(&Size::ALL, |range_size| {
+        rsx! {
+            Range { label: "Volume", range_size }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "range", &html);
+}
+
+#[test]
+fn rating() {
+    let mut html = variants(
+        &[RatingMask::Star, RatingMask::Star2, RatingMask::Heart],
+        |mask| {
+            rsx! {
+                Rating { name: "score", value: 2.0, mask, count: 3 }
+            }
+        },
+    );
+    html += &variants(&Size::ALL, |rating_size| {
+        rsx! {
+            Rating { name: "score", rating_size, count: 1 }
+        }
+    });
+    html += &render_fragment(rsx! {
+        Rating {
+            name: "score",
+            value: 1.5,
+            half: true,
+            count: 2,
+            rating_color: Color::Warning,
+        }
+        Rating { name: "average", value: 3.7, half: true, read_only: true }
+        Rating { name: "average", read_only: true, count: 2 }
+    });
+    assert_html_snapshot(SNAPSHOTS, "rating", &html);
+}
+
+#[test]
+fn relative_time() {
+    let formats = [
+        RelativeTimeFormat::Datetime,
+        RelativeTimeFormat::Relative,
+        RelativeTimeFormat::Duration,
+        RelativeTimeFormat::Auto,
+        RelativeTimeFormat::Micro,
+        RelativeTimeFormat::Elapsed,
+    ];
+    let html = variants(&formats, |format| {
+        rsx! {
+            RelativeTime { format, datetime: "2024-01-01T00:00:00Z" }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "relative_time", &html);
+}
+
+#[test]
+fn select() {
+    let html = variants(&Size::ALL, |select_size| {
+        rsx! {
+            Select { label: "Role", select_size, name: "role",
+                SelectOption { value: "admin", "Admin" }
+                SelectOption { value: "user", selected: true, "User" }
+            }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "select", &html);
+}
+
+#[test]
+fn select_field_value() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "select_field_value",
+        rsx! {
+            Select { label: "Plan", field: FieldState::new("plan").value("b").error("Upgrade required"),
+                SelectOption { value: "a", selected: true, "A" }
+                SelectOption { value: "b", "B" }
+                SelectOption { value: "c", "C" }
+            }
+        },
+    );
+}
+
+#[test]
+fn stats() {
+    let html = variants(
+        &[StatsDirection::Horizontal, StatsDirection::Vertical],
+        |direction| {
+            rsx! {
+                Stats {
+                    direction,
+                    metrics: vec![
+                        Metric::new("Revenue", "$89,400")
+                            .description("21% more than last month")
+                            .trend(Trend::Up)
+                            .sparkline(vec![3.0, 5.0, 4.0, 8.0]),
+                        Metric::new("Churn", "2.1%").trend(Trend::Down),
+                        Metric::new("Costs", "$12,000")
+                            .trend(Trend::Down)
+                            .good(Trend::Down),
+                    ],
+                    Stat {
+                        title: "Users",
+                        value: "4,200",
+                        description: "Jan 1st - Feb 1st",
+                        figure: rsx! {
+                            svg { class: "size-8" }
+                        },
+                    }
+                }
+            }
+        },
+    );
+    assert_html_snapshot(SNAPSHOTS, "stats", &html);
+}
+
+#[test]
+fn steps() {
+    let html = variants(
+        &[StepsDirection::Horizontal, StepsDirection::Vertical],
+        |direction| {
+            rsx! {
+                Steps { direction,
+                    Step { step_color: Color::Primary, "Register" }
+                    Step { step_color: Color::Primary, content: "✓", "Choose plan" }
+                    Step { "Purchase" }
+                }
+            }
+        },
+    );
+    assert_html_snapshot(SNAPSHOTS, "steps", &html);
+}
+
+#[test]
+fn wizard() {
+    let html = variants(&[0, 1, 2], |current| {
+        rsx! {
+            Wizard {
+                action: "/onboarding",
+                current,
+                answers: vec![
+                    ("company".to_string(), "Acme".to_string()),
+                    ("plan".to_string(), "team".to_string()),
+                ],
+                steps: vec![
+                    WizardStep::new(
+                            "Company",
+                            rsx! {
+                                Input { label: "Company", name: "company" }
+                            },
+                        )
+                        .field("company"),
+                    WizardStep::new(
+                            "Plan",
+                            rsx! {
+                                Input { label: "Plan", name: "plan" }
+                            },
+                        )
+                        .field("plan"),
+                    WizardStep::new(
+                            "Confirm",
+                            rsx! {
+                                p { "All done?" }
+                            },
+                        )
+                        .content("✓"),
+                ],
+            }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "wizard", &html);
+}
+
+#[test]
+fn tab_container() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "tab_container",
+        rsx! {
+            TabContainer {
+                TabPanel { name: "tabs", tab_name: "One", checked: true, "First" }
+                TabPanel { name: "tabs", tab_name: "Two", "Second" }
+            }
+        },
+    );
+}
+
+#[test]
+fn table() {
+    let html = variants(&Size::ALL, |table_size| {
+        rsx! {
+            Table { zebra: true, pin_rows: true, pin_cols: true, table_size,
+                TableHead {
+                    TableRow {
+                        TableCell { header: true, "Name" }
+                    }
+                }
+                TableBody {
+                    TableRow { hover: true,
+                        TableCell { "Daisy" }
+                    }
+                }
+            }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "table", &html);
+}
+
+#[derive(Clone, PartialEq)]
+struct User {
+    name: &'static str,
+    email: &'static str,
+}
+
+fn user_columns() -> Vec<Column<User>> {
+    vec![
+        Column::new("Name", |user: &User| rsx! { "{user.name}" }).sortable("name"),
+        Column::new("Email", |user: &User| rsx! { "{user.email}" }),
+    ]
+}
+
+#[test]
+fn data_table() {
+    let sorts = [
+        None,
+        Some(SortDirection::Ascending),
+        Some(SortDirection::Descending),
+    ];
+    let html = variants(&sorts, |direction| {
+        rsx! {
+            DataTable {
+                rows: vec![User {
+                    name: "Daisy",
+                    email: "daisy@example.com",
+                }],
+                columns: user_columns(),
+                sort_url: "/users?q=d&sort=email#list",
+                sort: direction.map(|direction| Sort {
+                    key: "name".to_string(),
+                    direction,
+                }),
+                empty_heading: "No users",
+            }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "data_table", &html);
+}
+
+#[test]
+fn data_table_pinned() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "data_table_pinned",
+        rsx! {
+            DataTable {
+                rows: vec![User {
+                    name: "Daisy",
+                    email: "daisy@example.com",
+                }],
+                columns: user_columns(),
+                pin_rows: true,
+                pin_cols: true,
+                empty_heading: "No users",
+            }
+        },
+    );
+}
+
+#[test]
+fn data_table_empty() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "data_table_empty",
+        rsx! {
+            DataTable {
+                rows: Vec::<User>::new(),
+                columns: user_columns(),
+                empty_heading: "No users",
+                empty_description: "Invite someone to get started",
+                empty_visual: "/users.svg",
+            }
+        },
+    );
+}
+
+#[test]
+fn text_area() {
+    let html = variants(&Size::ALL, |textarea_size| {
+        rsx! {
+            TextArea { label: "Bio", textarea_size, name: "bio" }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "text_area", &html);
+}
+
+#[test]
+fn theme_controller() {
+    let styles = [
+        ThemeControllerStyle::Radio,
+        ThemeControllerStyle::Toggle,
+        ThemeControllerStyle::Dropdown,
+    ];
+    let html = variants(&styles, |controller_style| {
+        rsx! {
+            ThemeController {
+                themes: vec![Theme::Dark, Theme::Corporate, Theme::Custom("acme".to_string())],
+                current: Theme::Dark,
+                controller_style,
+            }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "theme_controller", &html);
+}
+
+#[test]
+fn time_line() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "time_line",
+        rsx! {
+            TimeLine { condensed: true,
+                TimeLineBadge { image_src: "/check.svg" }
+                TimeLineBody { "Deployed" }
+            }
+        },
+    );
+}
+
+#[test]
+fn timeline() {
+    let directions = [TimelineDirection::Horizontal, TimelineDirection::Vertical];
+    let html = variants(&directions, |direction| {
+        rsx! {
+            Timeline { direction, compact: true, snap_icon: true,
+                TimelineItem {
+                    TimelineStart { boxed: true, "2024" }
+                    TimelineMiddle { "•" }
+                    TimelineEnd { "Launched" }
+                }
+            }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "timeline", &html);
+}
+
+#[test]
+fn toast() {
+    let positions = [
+        (ToastHorizontal::Start, ToastVertical::Top),
+        (ToastHorizontal::Center, ToastVertical::Middle),
+        (ToastHorizontal::End, ToastVertical::Bottom),
+    ];
+    let html = variants(&positions, |(horizontal, vertical)| {
+        rsx! {
+            Toast { horizontal, vertical, "Hello" }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "toast", &html);
+}
+
+#[test]
+fn flashes() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "flashes",
+        rsx! {
+            Flashes {
+                flashes: vec![Flash::success("Saved"), Flash::error("Upload failed")],
+                vertical: ToastVertical::Top,
+            }
+        },
+    );
+}
+
+#[test]
+fn htmx() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "htmx",
+        rsx! {
+            Button {
+                hx: Hx::post("/jobs/42/retry")
+                    .target("#job-42")
+                    .swap(HxSwap::OuterHtml)
+                    .indicator("#spinner"),
+                "Retry"
+            }
+            Button { hx: Hx::delete("/jobs/42").swap(HxSwap::Delete), "Delete" }
+            NavItem {
+                href: "/projects",
+                icon: "/icons/projects.svg",
+                title: "Projects",
+                navigation: Hx::new().push_url(),
+            }
+            DropDown { button_text: "Menu",
+                DropDownLink { href: "/settings", hx: Hx::new(), "Settings" }
+            }
+            NumberedPagination {
+                current_page: 2,
+                total_pages: 3,
+                page_url: PageUrl::query("/users?page=1"),
+                hx: Hx::new().target("#users").push_url(),
+            }
+            Modal {
+                trigger_id: "rename",
+                submit_action: "/projects/1",
+                hx: Hx::new().target("#project-1").swap(HxSwap::OuterHtml),
+                ModalBody { "Rename" }
+            }
+        },
+    );
+}
+
+#[test]
+fn oob_swap() {
+    assert_snapshot(
+        SNAPSHOTS,
+        "oob_swap",
+        rsx! {
+            OobSwap { id: "cart-count", "3" }
+            OobSwap { id: "notifications", swap: HxSwap::BeforeEnd,
+                li { "New message" }
+            }
+        },
+    );
+}
+
+#[test]
+fn toggle() {
+    let mut html = variants(&Color::ALL, |toggle_scheme| {
+        rsx! {
+            Toggle { label: "Notifications", toggle_scheme }
+        }
+    });
+    html += &variants(&Size::ALL, |toggle_size| {
+        rsx! {
+            Toggle { label: "Notifications", toggle_size }
+        }
+    });
+    html += &render_fragment(rsx! {
+        Toggle { label: "Notifications", field: FieldState::new("notify").value("true") }
+    });
+    assert_html_snapshot(SNAPSHOTS, "toggle", &html);
+}
+
+#[test]
+fn tooltip() {
+    let html = variants(&Color::ALL, |tooltip_color| {
+        rsx! {
+            ToolTip { text: "Help", tooltip_color, "?" }
+        }
+    });
+    assert_html_snapshot(SNAPSHOTS, "tooltip", &html);
+}
+
+mod marketing {
+    use daisy_rsx::marketing::benefits::Benefits;
+    use daisy_rsx::marketing::customer_logos::Customers;
+    use daisy_rsx::marketing::extra_footer::ExtraFooter;
+    use daisy_rsx::marketing::faq_accordian::{Faq, FaqText};
+    use daisy_rsx::marketing::features::{Feature, Features};
+    use daisy_rsx::marketing::hero::Hero;
+    use daisy_rsx::marketing::image_feature::ImageFeature;
+    use daisy_rsx::marketing::problem_solution::ProblemSolution;
+    use daisy_rsx::marketing::quad_feature::QuadFeature;
+    use daisy_rsx::marketing::security::Security;
+    use daisy_rsx::marketing::small_image_feature::SmallImageFeature;
+    use daisy_rsx::marketing::team::Team;
+    use daisy_rsx::marketing::testamonials::Testamonials;
+    use daisy_rsx::marketing::video_hero::VideoHero;
+    use daisy_rsx::marketing::webinar::WebinarHeader;
+    use daisy_rsx::snapshot::assert_snapshot;
+    use dioxus::prelude::*;
+
+    use super::SNAPSHOTS;
+
+    #[test]
+    fn benefits() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_benefits",
+            rsx! {
+                Benefits {
+                    title: "Why us",
+                    subtitle: "Benefits",
+                    benefit1: "Fast",
+                    benefit1_desc: "Quick to set up",
+                    benefit2: "Secure",
+                    benefit2_desc: "Runs on your servers",
+                    benefit3: "Open",
+                    benefit3_desc: "MIT licensed",
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn customer_logos() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_customer_logos",
+            rsx! {
+                Customers { class: "mt-8" }
+            },
+        );
+    }
+
+    #[test]
+    fn extra_footer() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_extra_footer",
+            rsx! {
+                ExtraFooter {
+                    title: "Get started",
+                    image: "/footer.png",
+                    cta: "Sign up",
+                    cta_url: "/signup",
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn faq() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_faq",
+            rsx! {
+                Faq {
+                    questions: vec![
+                        FaqText {
+                            question: "Is it free?".to_string(),
+                            answer: "Yes".to_string(),
+                        },
+                        FaqText {
+                            question: "Can I self host?".to_string(),
+                            answer: "Also yes".to_string(),
+                        },
+                    ],
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn features() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_features",
+            rsx! {
+                Features {
+                    title: "Features",
+                    description: "Everything you need",
+                    features: vec![
+                        Feature {
+                            title: "Chat".to_string(),
+                            description: "Talk to your data".to_string(),
+                        },
+                        Feature {
+                            title: "Teams".to_string(),
+                            description: "Share with colleagues".to_string(),
+                        },
+                    ],
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn hero() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_hero",
+            rsx! {
+                Hero {
+                    title: "Build faster",
+                    subtitle: "With server rendered components",
+                    cta: "Start",
+                    cta_link: "/start",
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn image_feature() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_image_feature",
+            rsx! {
+                ImageFeature {
+                    title: "Analytics",
+                    sub_title: "Insight",
+                    text: "See what happens",
+                    title1: "One",
+                    text1: "First",
+                    title2: "Two",
+                    text2: "Second",
+                    title3: "Three",
+                    text3: "Third",
+                    image: "/feature.png",
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn problem_solution() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_problem_solution",
+            rsx! {
+                ProblemSolution {
+                    image: "/problem.png",
+                    title: "The problem",
+                    problem: "Data leaks",
+                    solution: "Self hosting",
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn quad_feature() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_quad_feature",
+            rsx! {
+                QuadFeature {
+                    title: "Platform",
+                    sub_title: "Features",
+                    text: "Four things",
+                    title1: "One",
+                    text1: "First",
+                    title2: "Two",
+                    text2: "Second",
+                    title3: "Three",
+                    text3: "Third",
+                    title4: "Four",
+                    text4: "Fourth",
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn security() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_security",
+            rsx! {
+                Security {}
+            },
+        );
+    }
+
+    #[test]
+    fn small_image_feature() {
+        let feature = |flip| {
+            rsx! {
+                SmallImageFeature {
+                    title: "Upload",
+                    sub_title: "Documents",
+                    text: "Drop files here",
+                    image: "/upload.png",
+                    flip,
+                }
+            }
+        };
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_small_image_feature",
+            rsx! {
+                {feature(false)}
+                {feature(true)}
+            },
+        );
+    }
+
+    #[test]
+    fn team() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_team",
+            rsx! {
+                Team {}
+            },
+        );
+    }
+
+    #[test]
+    fn testamonials() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_testamonials",
+            rsx! {
+                Testamonials {
+                    text1: "Great",
+                    job1: "CTO",
+                    person1: "Ada",
+                    img1: "/ada.png",
+                    text2: "Superb",
+                    job2: "CEO",
+                    person2: "Grace",
+                    img2: "/grace.png",
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn video_hero() {
+        assert_snapshot(
+            SNAPSHOTS,
+            "marketing_video_hero",
+            rsx! {
+                VideoHero {
+                    title: "Watch",
+                    subtitle: "A short demo",
+                    video: "https://www.youtube.com/embed/demo",
+                    claim: "Open source",
+                    cta: "Try it",
+                    cta_link: "/try",
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn webinar_header() {
+        assert_snapshot(SNAPSHOTS, "marketing_webinar_header", WebinarHeader());
+    }
+}
@@ -0,0 +1,56 @@
+use daisy_rsx::app_layout::AppLayoutProps;
+use daisy_rsx::ssr::{render_content, render_page, write_page_io};
+use daisy_rsx::*;
+use dioxus::prelude::*;
+
+fn props() -> AppLayoutProps {
+    AppLayoutProps::builder()
+        .title("Settings".to_string())
+        .stylesheets(vec![])
+        .theme(Some(Theme::Dark))
+        .meta(PageMeta::new().lang("de").description("Account settings"))
+        .header(rsx! {})
+        .sidebar(rsx! {})
+        .sidebar_header(rsx! {})
+        .sidebar_footer(rsx! {})
+        .children(rsx! {
+            p { "Body" }
+        })
+        .build()
+}
+
+#[test]
+fn render_page_writes_lang_and_theme_on_html() {
+    let html = render_page(props());
+    assert!(html.starts_with("<!DOCTYPE html><html lang=\"de\" data-theme=\"dark\"><head>"));
+    assert!(html.ends_with("</body></html>"));
+}
+
+#[test]
+fn write_page_io_matches_render_page() {
+    let mut bytes = Vec::new();
+    write_page_io(&mut bytes, props()).unwrap();
+    assert_eq!(String::from_utf8(bytes).unwrap(), render_page(props()));
+}
+
+#[test]
+fn render_content_leaves_html_and_head_to_the_page() {
+    let html = render_content(props());
+    assert!(html.starts_with("<title>Settings</title>"));
+    assert!(html.contains("<p>Body</p>"));
+    assert!(!html.contains("<html"));
+    assert!(!html.contains("data-theme"));
+    assert!(!html.contains("Account settings"));
+}
+
+#[test]
+fn app_layout_alone_puts_lang_and_theme_on_body() {
+    let mut dom = VirtualDom::new_with_props(AppLayout, props());
+    dom.rebuild_in_place();
+    let html = dioxus_ssr::render(&dom);
+    assert!(html.starts_with("<head><title>Settings</title>"));
+    assert!(
+        html.contains("<body lang=\"de\" data-theme=\"dark\">"),
+        "{html}"
+    );
+}
@@ -0,0 +1,43 @@
+use daisy_rsx::ssr::render_fragment;
+use daisy_rsx::{Stat, Trend, sparkline_points};
+use dioxus::prelude::*;
+
+#[test]
+fn sparkline_fills_the_box() {
+    assert_eq!(
+        sparkline_points(&[1.0, 3.0, 2.0], 100, 20),
+        "0.0,20.0 50.0,0.0 100.0,10.0"
+    );
+}
+
+#[test]
+fn flat_and_short_sparklines() {
+    assert_eq!(sparkline_points(&[5.0, 5.0], 10, 10), "0.0,5.0 10.0,5.0");
+    assert_eq!(sparkline_points(&[5.0], 10, 10), "0.0,5.0");
+    assert_eq!(sparkline_points(&[], 10, 10), "");
+}
+
+#[test]
+fn sparkline_skips_non_finite_values() {
+    assert_eq!(
+        sparkline_points(&[1.0, f64::NAN, 3.0, f64::INFINITY, 2.0], 100, 20),
+        "0.0,20.0 50.0,0.0 100.0,10.0"
+    );
+    assert_eq!(sparkline_points(&[f64::NAN, f64::NEG_INFINITY], 10, 10), "");
+}
+
+#[test]
+fn trends_are_colored_by_the_good_direction() {
+    assert_eq!(Trend::Up.color_class(Trend::Up), "text-success");
+    assert_eq!(Trend::Down.color_class(Trend::Up), "text-error");
+    assert_eq!(Trend::Down.color_class(Trend::Down), "text-success");
+    assert_eq!(Trend::Up.color_class(Trend::Down), "text-error");
+}
+
+#[test]
+fn arrow_without_description_has_no_trailing_space() {
+    let html = render_fragment(rsx! {
+        Stat { title: "Churn", value: "2.1%", trend: Trend::Down }
+    });
+    assert!(html.contains(">↘︎</div>"), "{html}");
+}
@@ -0,0 +1,33 @@
+use daisy_rsx::ssr::render_fragment;
+use daisy_rsx::*;
+use dioxus::prelude::*;
+
+#[test]
+fn wizard_actions_round_trip() {
+    for action in [WizardAction::Back, WizardAction::Next, WizardAction::Finish] {
+        assert_eq!(action.to_string().parse(), Ok(action));
+    }
+    assert!("skip".parse::<WizardAction>().is_err());
+}
+
+#[test]
+fn enter_submits_next_not_back() {
+    let html = render_fragment(rsx! {
+        Wizard {
+            action: "/onboarding",
+            current: 1,
+            steps: vec![
+                WizardStep::new("Company", rsx! {}),
+                WizardStep::new("Plan", rsx! {
+                    input { name: "plan" }
+                }),
+                WizardStep::new("Confirm", rsx! {}),
+            ],
+        }
+    });
+    let first_submit = html.find("type=\"submit\"").expect("a submit button");
+    let button = &html[html[..first_submit].rfind("<button").unwrap()..];
+    let button = &button[..button.find('>').unwrap()];
+    assert!(button.contains("value=\"next\""), "{button}");
+    assert!(html.contains("value=\"back\""));
+}
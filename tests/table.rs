@@ -0,0 +1,29 @@
+use daisy_rsx::{Sort, SortDirection};
+
+#[test]
+fn sort_direction_round_trips() {
+    for direction in [SortDirection::Ascending, SortDirection::Descending] {
+        assert_eq!(direction.to_string().parse(), Ok(direction));
+    }
+    assert!("sideways".parse::<SortDirection>().is_err());
+}
+
+#[test]
+fn sort_from_query() {
+    assert_eq!(
+        Sort::from_query("/users?q=d&sort=created%20at&order=desc#list"),
+        Some(Sort {
+            key: "created at".to_string(),
+            direction: SortDirection::Descending,
+        })
+    );
+    assert_eq!(
+        Sort::from_query("sort=name&order=bogus"),
+        Some(Sort {
+            key: "name".to_string(),
+            direction: SortDirection::Ascending,
+        })
+    );
+    assert_eq!(Sort::from_query("?order=desc"), None);
+    assert_eq!(Sort::from_query("/users?sort="), None);
+}
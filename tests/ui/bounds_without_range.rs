@@ -0,0 +1,9 @@
+use daisy_rsx::DaisyForm;
+
+#[derive(DaisyForm)]
+struct Order {
+    #[daisy(widget = "number", step = 0.5)]
+    weight: f64,
+}
+
+fn main() {}
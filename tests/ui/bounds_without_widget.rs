@@ -0,0 +1,9 @@
+use daisy_rsx::DaisyForm;
+
+#[derive(DaisyForm)]
+struct Order {
+    #[daisy(min = 1, max = 10)]
+    quantity: u8,
+}
+
+fn main() {}
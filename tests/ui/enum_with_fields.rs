@@ -0,0 +1,9 @@
+use daisy_rsx::DaisyForm;
+
+#[derive(DaisyForm)]
+enum Plan {
+    Free,
+    Custom(u32),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use daisy_rsx::DaisyForm;
+
+#[derive(DaisyForm)]
+enum Plan {
+    #[daisy(hint = "No card needed")]
+    Free,
+    Pro,
+}
+
+fn main() {}
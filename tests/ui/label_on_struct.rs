@@ -0,0 +1,9 @@
+use daisy_rsx::DaisyForm;
+
+#[derive(DaisyForm)]
+#[daisy(label = "Profile")]
+struct Profile {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use daisy_rsx::DaisyForm;
+
+#[derive(DaisyForm)]
+#[daisy(legend = "Plan")]
+enum Plan {
+    Free,
+    Pro,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use daisy_rsx::DaisyForm;
+
+#[derive(DaisyForm)]
+struct Profile {
+    #[daisy(widget = "select")]
+    plan: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use daisy_rsx::DaisyForm;
+
+#[derive(DaisyForm)]
+struct Profile(String);
+
+fn main() {}
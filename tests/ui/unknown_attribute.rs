@@ -0,0 +1,9 @@
+use daisy_rsx::DaisyForm;
+
+#[derive(DaisyForm)]
+struct Profile {
+    #[daisy(placeholder = "Ada")]
+    name: String,
+}
+
+fn main() {}
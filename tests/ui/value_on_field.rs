@@ -0,0 +1,9 @@
+use daisy_rsx::DaisyForm;
+
+#[derive(DaisyForm)]
+struct Profile {
+    #[daisy(value = "ada")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use daisy_rsx::DaisyForm;
+
+#[derive(DaisyForm)]
+#[daisy(widget = "textarea")]
+struct Profile {
+    bio: String,
+}
+
+fn main() {}